@@ -5,10 +5,8 @@ import { Chain } from "./chain"
 
 export const DAY = 24 * 60 * 60
 export const NFT_AMOUNT = 3500
-const DAILY_REWARD = 0.07438286
-const MAX_REWARD = 119830787460
-const LAST_DAY = 180
 const PRECISION = 100000000
+const BASIS_POINTS = 10000
 
 export class StakeInfo {
   timestamp: number
@@ -17,6 +15,19 @@ export class StakeInfo {
   active: boolean
   withdrawn: number
   harvested: number
+  accrued: number
+  checkpoint: number
+  whitelist: PublicKey
+  rewardMultiplier: number
+  boost: number
+  reserved: number
+  frozen: boolean
+  lockUntil: number
+  lockMultiplier: number
+  unbondingUntil: number
+  receipt: boolean
+  stakes: number
+  raisedAt: number
 
   constructor(buf: Buffer) {
     let offset = 0
@@ -31,6 +42,211 @@ export class StakeInfo {
     this.withdrawn = Number(buf.readBigUInt64LE(offset))
     offset += 8
     this.harvested = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.accrued = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.checkpoint = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.whitelist = new PublicKey(buf.slice(offset, offset + 32))
+    offset += 32
+    this.rewardMultiplier = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.boost = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.reserved = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.frozen = buf.readUInt8(offset) !== 0
+    offset += 1
+    this.lockUntil = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.lockMultiplier = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.unbondingUntil = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.receipt = buf.readUInt8(offset) !== 0
+    offset += 1
+    this.stakes = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.raisedAt = Number(buf.readBigUInt64LE(offset))
+  }
+}
+
+export class StakerInfo {
+  staker: PublicKey
+  positions: number
+  rewardsClaimed: number
+  mints: PublicKey[]
+  owed: number
+
+  constructor(buf: Buffer) {
+    let offset = 0
+    this.staker = new PublicKey(buf.slice(offset, offset + 32))
+    offset += 32
+    this.positions = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.rewardsClaimed = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    const count = buf.readUInt32LE(offset)
+    offset += 4
+    this.mints = []
+    for (let i = 0; i < count; i++) {
+      this.mints.push(new PublicKey(buf.slice(offset, offset + 32)))
+      offset += 32
+    }
+    this.owed = Number(buf.readBigUInt64LE(offset))
+  }
+}
+
+export class WhitelistCount {
+  whitelist: PublicKey
+  active: number
+
+  constructor(whitelist: PublicKey, active: number) {
+    this.whitelist = whitelist
+    this.active = active
+  }
+}
+
+export class PoolStats {
+  totalActive: number
+  lifetimeStakes: number
+  rewardsDistributed: number
+  whitelists: WhitelistCount[]
+
+  constructor(buf: Buffer) {
+    let offset = 0
+    this.totalActive = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.lifetimeStakes = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.rewardsDistributed = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    const count = buf.readUInt32LE(offset)
+    offset += 4
+    this.whitelists = []
+    for (let i = 0; i < count; i++) {
+      const whitelist = new PublicKey(buf.slice(offset, offset + 32))
+      offset += 32
+      this.whitelists.push(new WhitelistCount(whitelist, Number(buf.readBigUInt64LE(offset))))
+      offset += 8
+    }
+  }
+}
+
+// `amount` scaled by a multiplier in basis points, mirrors the program's `apply_multiplier`
+function applyMultiplier(amount: number, multiplier: number): number {
+  return Math.floor((amount * multiplier) / BASIS_POINTS)
+}
+
+export class RewardSchedule {
+  interval: number
+  payoutPerInterval: number
+  warmupIntervals: number
+  periodIntervals: number
+  maxPayoutPerNft: number
+
+  constructor(buf: Buffer, offset: number) {
+    this.interval = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.payoutPerInterval = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.warmupIntervals = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.periodIntervals = Number(buf.readBigUInt64LE(offset))
+    offset += 8
+    this.maxPayoutPerNft = Number(buf.readBigUInt64LE(offset))
+  }
+
+  // total reward of a stake that is `stakedFor` seconds old, mirrors the program's `cumulative_reward`
+  public cumulativeReward(stakedFor: number): number {
+    const periods = Math.floor(stakedFor / this.interval)
+
+    if (periods <= this.warmupIntervals) {
+      return 0
+    } else if (periods <= this.periodIntervals) {
+      const paid = periods - this.warmupIntervals
+      return Math.min((this.payoutPerInterval * paid * (paid + 1)) / 2, this.maxPayoutPerNft)
+    }
+
+    return this.maxPayoutPerNft
+  }
+}
+
+export class ScheduleEpoch {
+  start: number
+  schedule: RewardSchedule
+
+  constructor(buf: Buffer, offset: number) {
+    this.start = Number(buf.readBigUInt64LE(offset))
+    this.schedule = new RewardSchedule(buf, offset + 8)
+  }
+}
+
+export class PoolInfo {
+  rewardMint: PublicKey
+  // every schedule the pool had, the last one prices new rewards
+  epochs: ScheduleEpoch[]
+
+  constructor(buf: Buffer) {
+    let offset = 8
+    this.rewardMint = new PublicKey(buf.slice(offset, offset + 32))
+    offset += 32
+    // vault bump
+    offset += 1
+    // boost root
+    offset += buf.readUInt8(offset) !== 0 ? 33 : 1
+    // require funded, max staked, soft staking
+    offset += 10
+    // early exit penalty
+    offset += buf.readUInt8(offset) !== 0 ? 9 : 1
+    // unbonding period, settle on transfer, mint receipts
+    offset += 10
+    // ledger
+    offset += 48
+    const tiers = buf.readUInt32LE(offset)
+    offset += 4 + tiers * 16
+    const epochs = buf.readUInt32LE(offset)
+    offset += 4
+    this.epochs = []
+    for (let i = 0; i < epochs; i++) {
+      this.epochs.push(new ScheduleEpoch(buf, offset))
+      offset += 48
+    }
+  }
+
+  // reward accrued between the checkpoint of the stake and `now`, mirrors the program's `calculate_reward`
+  public calculateReward(stakeInfo: StakeInfo, now: number): number {
+    const multiplier = applyMultiplier(
+      applyMultiplier(stakeInfo.rewardMultiplier, stakeInfo.boost),
+      stakeInfo.lockMultiplier
+    )
+    let earned = stakeInfo.harvested + stakeInfo.accrued
+    let reward = 0
+
+    this.epochs.forEach((epoch, i) => {
+      const next = this.epochs[i + 1]
+      const end = next ? Math.min(next.start, now) : now
+      const start = Math.max(epoch.start, stakeInfo.checkpoint, stakeInfo.timestamp)
+
+      if (start >= end) {
+        return
+      }
+
+      const schedule = epoch.schedule
+      const accrued = Math.min(
+        Math.max(
+          applyMultiplier(schedule.cumulativeReward(end - stakeInfo.timestamp), multiplier) -
+            applyMultiplier(schedule.cumulativeReward(start - stakeInfo.timestamp), multiplier),
+          0
+        ),
+        Math.max(applyMultiplier(schedule.maxPayoutPerNft, multiplier) - earned, 0)
+      )
+
+      earned += accrued
+      reward += accrued
+    })
+
+    return reward
   }
 }
 
@@ -58,12 +274,14 @@ export class StakingPageInfo {
 
 export class Client {
   public programId: PublicKey
+  public poolId: number
   public splAssociatedTokenProgramID: PublicKey
   public connection: Connection
   public chain: Chain
 
   constructor() {
     this.programId = new PublicKey("")
+    this.poolId = 0
     this.splAssociatedTokenProgramID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
     this.connection = new Connection(clusterApiUrl("mainnet-beta"))
     this.chain = new Chain(this.connection)
@@ -93,8 +311,9 @@ export class Client {
   }
 
   public async getStakeInfo(nft: PublicKey): Promise<StakeInfo | undefined> {
+    const pool = await this.getPool()
     const result = await PublicKey.findProgramAddress(
-      [new PublicKey(nft).toBuffer()],
+      [pool.toBuffer(), new PublicKey(nft).toBuffer()],
       this.programId
     )
     let acc = await this.connection.getAccountInfo(result[0])
@@ -121,62 +340,28 @@ export class Client {
 
   public async getExpectedInterest(nft: PublicKey): Promise<number> {
     const stakeInfo = await this.getStakeInfo(nft)
+    const poolInfo = await this.getPoolInfo()
 
-    if (!stakeInfo) {
+    if (!stakeInfo || !poolInfo) {
       return 0
     }
 
     const now = await this.chain.timestamp()
 
-    const timeInStake = now - stakeInfo.timestamp
-    const periods = Math.floor(timeInStake / DAY)
-
-    const reward = periods > LAST_DAY ? 0 : periods * DAILY_REWARD
-
-    if (stakeInfo.harvested + reward * PRECISION > MAX_REWARD) {
-      return (MAX_REWARD - stakeInfo.harvested) / PRECISION
-    }
-
-    return reward
+    return (stakeInfo.accrued + poolInfo.calculateReward(stakeInfo, now)) / PRECISION
   }
 
   public async getCurrentReward(nft: PublicKey): Promise<boolean> {
     const stakeInfo = await this.getStakeInfo(nft)
+    const poolInfo = await this.getPoolInfo()
 
-    if (!stakeInfo) {
+    if (!stakeInfo || !poolInfo) {
       return false
     }
 
     const now = await this.chain.timestamp()
 
-    const timeInStake = now - stakeInfo.timestamp
-    const periods = Math.floor(timeInStake / DAY)
-
-    if (periods <= 1) {
-      return false
-    }
-
-    const withdrawn = stakeInfo.withdrawn
-    const harvested = stakeInfo.harvested
-
-    if (harvested >= MAX_REWARD) {
-      return false
-    }
-
-    let reward = 0
-    if (periods >= LAST_DAY) {
-      reward = MAX_REWARD
-    } else {
-      for (let day = 2; day <= periods; day++) {
-        reward += DAILY_REWARD * PRECISION * (day - 1)
-        if (reward > withdrawn) {
-          return true
-        }
-      }
-      return false
-    }
-
-    return reward - withdrawn > 0
+    return stakeInfo.accrued + poolInfo.calculateReward(stakeInfo, now) > 0
   }
 
   public async getWalletPixelNFTs(pubkey: PublicKey): Promise<Nft[]> {
@@ -186,34 +371,81 @@ export class Client {
     )
   }
 
+  public async getPool(): Promise<PublicKey> {
+    const poolId = Buffer.alloc(8)
+    poolId.writeBigUInt64LE(BigInt(this.poolId))
+    const result = await PublicKey.findProgramAddress([new Buffer("pool"), poolId], this.programId)
+    return result[0]
+  }
+
   public async getVault(): Promise<PublicKey> {
-    const result = await PublicKey.findProgramAddress([new Buffer("vault")], this.programId)
+    const pool = await this.getPool()
+    const result = await PublicKey.findProgramAddress(
+      [new Buffer("vault"), pool.toBuffer()],
+      this.programId
+    )
     return result[0]
   }
 
-  public async getStakedNftsForOwner(owner: PublicKey): Promise<Nft[]> {
-    const vault = await this.getVault()
-    const Nfts: Nft[] = await this.getWalletPixelNFTs(vault)
+  public async getPoolInfo(): Promise<PoolInfo | undefined> {
+    const pool = await this.getPool()
+    let acc = await this.connection.getAccountInfo(pool)
+    if (!acc) {
+      return undefined
+    } else {
+      return new PoolInfo(acc.data)
+    }
+  }
 
-    let ownerNfts: Nft[] = []
+  public async getStats(): Promise<PoolStats | undefined> {
+    const pool = await this.getPool()
+    const result = await PublicKey.findProgramAddress(
+      [new Buffer("stats"), pool.toBuffer()],
+      this.programId
+    )
+    let acc = await this.connection.getAccountInfo(result[0])
+    if (!acc) {
+      return undefined
+    } else {
+      return new PoolStats(acc.data)
+    }
+  }
 
-    for (const nft of Nfts) {
-      const stakeInfo = await this.getStakeInfo(nft.mint)
-      if (stakeInfo != undefined) {
-        if (stakeInfo.staker.toString() == owner.toString()) {
-          ownerNfts.push(nft)
-        }
-      }
+  public async getStakerInfo(owner: PublicKey): Promise<StakerInfo | undefined> {
+    const pool = await this.getPool()
+    const result = await PublicKey.findProgramAddress(
+      [new Buffer("staker"), pool.toBuffer(), owner.toBuffer()],
+      this.programId
+    )
+    let acc = await this.connection.getAccountInfo(result[0])
+    if (!acc) {
+      return undefined
+    } else {
+      return new StakerInfo(acc.data)
+    }
+  }
+
+  public async getStakedNftsForOwner(owner: PublicKey): Promise<Nft[]> {
+    const stakerInfo = await this.getStakerInfo(owner)
+    if (stakerInfo == undefined) {
+      return []
     }
 
-    return ownerNfts
+    const mints = stakerInfo.mints.map((mint) => mint.toString())
+    const vault = await this.getVault()
+    // soft staked NFTs stay frozen in the owner's wallet
+    const Nfts: Nft[] = [
+      ...(await this.getWalletPixelNFTs(vault)),
+      ...(await this.getWalletPixelNFTs(owner)),
+    ]
+
+    return Nfts.filter((nft) => mints.includes(nft.mint.toString()))
   }
 
   public async getStakedNftsAmount(): Promise<number> {
-    const vault = await this.getVault()
-    const Nfts: Nft[] = await this.getWalletPixelNFTs(vault)
+    const stats = await this.getStats()
 
-    return Nfts.length
+    return stats ? stats.totalActive : 0
   }
 
   public getStakedNftsSupply(amount: number): number {
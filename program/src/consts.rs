@@ -2,15 +2,34 @@ use crate::Timestamp;
 
 pub const NFT_AMOUNT: u64 = 3500;
 
+// Default reward schedule of a new pool
 pub const REWARD_PERIOD: u64 = 180;
 
+pub const WARMUP_PERIOD: u64 = 1;
+
 pub const SECONDS_IN_THE_DAY: Timestamp = 24 * 60 * 60;
 
 pub const MAX_PAYOUT_PER_NFT: u64 = 119830787460;
 pub const PAYOUT_PER_DAY: u64 = 7438286;
 
+/// Schedule epochs a pool account is sized for, the creation opens the first one
+pub const MAX_SCHEDULE_EPOCHS: usize = 64;
+
+pub const MAX_STATS_WHITELISTS: usize = 64;
+
+pub const MAX_STAKER_POSITIONS: usize = 32;
+
+pub const MAX_LOCK_TIERS: usize = 8;
+
+/// Maximum size of a serialized transaction
+pub const PACKET_DATA_SIZE: usize = 1232;
+
+pub const BASIS_POINTS: u64 = 10_000;
+
 pub const VAULT: &[u8] = "vault".as_bytes();
 pub const WHITELIST: &[u8] = "whitelist".as_bytes();
-
-pub const ADMIN: &str = "";
-pub const REWARD_MINT: &str = "";
+pub const CONFIG: &[u8] = "config".as_bytes();
+pub const POOL: &[u8] = "pool".as_bytes();
+pub const STATS: &[u8] = "stats".as_bytes();
+pub const STAKER: &[u8] = "staker".as_bytes();
+pub const RECEIPT: &[u8] = "receipt".as_bytes();
@@ -19,6 +19,56 @@ pub enum ContractError {
     InactiveStaking,
     #[error("Nft is not whitelisted")]
     WhitelistError,
+    #[error("Platform is already initialized")]
+    AlreadyInitialized,
+    #[error("Platform is not initialized")]
+    NotInitialized,
+    #[error("There is no pending admin to accept")]
+    NoPendingAdmin,
+    #[error("Pool already exists")]
+    PoolAlreadyExists,
+    #[error("Pool does not exist")]
+    PoolNotFound,
+    #[error("Invalid reward schedule")]
+    InvalidSchedule,
+    #[error("Reward schedule history is full")]
+    ScheduleHistoryFull,
+    #[error("Operation is paused")]
+    Paused,
+    #[error("Nft is not whitelisted or its whitelist entry is disabled")]
+    WhitelistDisabled,
+    #[error("Reward multiplier must be greater than zero")]
+    InvalidMultiplier,
+    #[error("Boost proof does not match the published Merkle root")]
+    InvalidProof,
+    #[error("Vault can't cover the maximum payout of the position")]
+    InsufficientRewards,
+    #[error("Amount exceeds the vault balance above the reward liability")]
+    SurplusExceeded,
+    #[error("Too many whitelist entries with active stakes")]
+    StatsFull,
+    #[error("Pool holds the maximum number of staked NFTs")]
+    PoolFull,
+    #[error("Wallet holds the maximum number of positions in the pool")]
+    StakerFull,
+    #[error("Lock tier doesn't exist in the pool")]
+    InvalidLockTier,
+    #[error("Position is locked and the pool doesn't allow early exit")]
+    StillLocked,
+    #[error("Pool has an unbonding period, request the unstake first")]
+    UnbondingRequired,
+    #[error("Position has no pending unstake request")]
+    NotUnbonding,
+    #[error("Unbonding period hasn't passed yet")]
+    StillUnbonding,
+    #[error("Recipient isn't a token account of the reward mint")]
+    InvalidRecipient,
+    #[error("Soft staked positions can't be transferred")]
+    SoftStaked,
+    #[error("Receipt token is missing or held by someone else")]
+    InvalidReceipt,
+    #[error("Position is held through its receipt token")]
+    ReceiptPosition,
 }
 
 impl From<ContractError> for ProgramError {
@@ -1,25 +1,460 @@
-use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use solana_program::borsh::try_from_slice_unchecked;
+use crate::consts::PACKET_DATA_SIZE;
+use crate::error::ContractError;
+use crate::state::batch::{
+    join_batch, unused_account, CLAIM_GROUP, FORCE_UNSTAKE_GROUP, STAKE_GROUP,
+};
+use crate::state::metadata::get_whitelist_key;
+use crate::state::receipt::get_receipt_address;
+use crate::state::soft_stake::get_edition_address;
+use crate::state::structs::{
+    Boost, Eligibility, LockTier, PauseFlags, PoolOptions, RewardSchedule, StakeEntry,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::bpf_loader_upgradeable;
 use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::message::Message;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
 pub enum PlatformInstruction {
-    GenerateVault,
-    AddToWhitelist,
-    Stake,
-    Unstake,
-    Claim,
+    GenerateVault {
+        pool_id: u64,
+    },
+    AddToWhitelist {
+        pool_id: u64,
+        eligibility: Eligibility,
+        reward_multiplier: u64,
+    },
+    Stake {
+        pool_id: u64,
+        eligibility: Eligibility,
+        boost: Option<Boost>,
+        lock_tier: Option<u8>,
+    },
+    /// Returns the NFT and pays the reward as far as the vault covers it. The unpaid rest, also all
+    /// of it during a claim pause, is owed to the wallet's staker account rather than the closed
+    /// position, so the NFT can be staked again right away, `ClaimAll` pays it
+    Unstake {
+        pool_id: u64,
+    },
+    /// Pays the reward of one active position, `StakerData::owed` is left to `ClaimAll`
+    Claim {
+        pool_id: u64,
+    },
+    InitializePlatform,
+    ProposeAdmin {
+        new_admin: Pubkey,
+    },
+    AcceptAdmin,
+    CreatePool {
+        pool_id: u64,
+        schedule: RewardSchedule,
+    },
+    /// Opens a new epoch priced with `schedule`. Epochs are never pruned, since any open position
+    /// may still accrue over them, so a pool takes `MAX_SCHEDULE_EPOCHS - 1` updates over its
+    /// lifetime and refuses further ones with `ScheduleHistoryFull`
+    UpdateRewardSchedule {
+        pool_id: u64,
+        schedule: RewardSchedule,
+    },
+    SetPauseFlags {
+        paused: PauseFlags,
+    },
+    RemoveFromWhitelist {
+        pool_id: u64,
+    },
+    UpdateWhitelist {
+        pool_id: u64,
+        reward_multiplier: u64,
+        enabled: bool,
+    },
+    SetBoostRoot {
+        pool_id: u64,
+        root: Option<[u8; 32]>,
+    },
+    DepositRewards {
+        pool_id: u64,
+        amount: u64,
+    },
+    SetPoolOptions {
+        pool_id: u64,
+        options: PoolOptions,
+    },
+    WithdrawSurplus {
+        pool_id: u64,
+        amount: u64,
+    },
+    StakeBatch {
+        pool_id: u64,
+        entries: Vec<StakeEntry>,
+    },
+    UnstakeBatch {
+        pool_id: u64,
+        count: u8,
+    },
+    ClaimBatch {
+        pool_id: u64,
+        count: u8,
+    },
+    /// Pays the active positions passed along with `StakerData::owed`, the only instruction
+    /// collecting what unstakes and forced unstakes left owed to the wallet
+    ClaimAll {
+        pool_id: u64,
+    },
+    SetLockTiers {
+        pool_id: u64,
+        tiers: Vec<LockTier>,
+    },
+    RequestUnstake {
+        pool_id: u64,
+    },
+    CompleteUnstake {
+        pool_id: u64,
+    },
+    ClaimTo {
+        pool_id: u64,
+    },
+    UnstakeTo {
+        pool_id: u64,
+    },
+    TransferPosition {
+        pool_id: u64,
+    },
+    ForceUnstake {
+        pool_id: u64,
+        count: u8,
+        settle: bool,
+    },
+}
+
+/// NFT of a batch instruction, `boost` and `lock_tier` are only used by `StakeBatch`,
+/// `owner` by `ForceUnstake`. `stakes` is `StakeData::stakes` of the NFT, 0 before its first stake.
+/// `frozen` and `receipt` are the flags of the position, for `StakeBatch` the pool's `soft_staking`
+/// and `mint_receipts`, the edition and receipt accounts are only passed when they are set
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchNft {
+    pub nft: Pubkey,
+    pub metadata: Pubkey,
+    pub metadata_data: Vec<u8>,
+    pub eligibility: Eligibility,
+    pub boost: Option<Boost>,
+    pub lock_tier: Option<u8>,
+    pub owner: Option<Pubkey>,
+    pub stakes: u64,
+    pub frozen: bool,
+    pub receipt: bool,
 }
 
 impl PlatformInstruction {
-    pub fn generate_vault(wallet_pubkey: Pubkey, program_id: Pubkey) -> Instruction {
-        let (vault_pda, _) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
+    pub fn initialize_platform(wallet_pubkey: Pubkey, program_id: Pubkey) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (program_data, _) =
+            Pubkey::find_program_address(&[&program_id.to_bytes()], &bpf_loader_upgradeable::id());
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::InitializePlatform,
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new(config, false),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(
+                    "SysvarRent111111111111111111111111111111111"
+                        .parse::<Pubkey>()
+                        .unwrap(),
+                    false,
+                ),
+            ],
+        )
+    }
+
+    pub fn propose_admin(
+        wallet_pubkey: Pubkey,
+        new_admin: Pubkey,
+        program_id: Pubkey,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::ProposeAdmin { new_admin },
+            vec![
+                AccountMeta::new_readonly(wallet_pubkey, true),
+                AccountMeta::new(config, false),
+            ],
+        )
+    }
+
+    pub fn accept_admin(wallet_pubkey: Pubkey, program_id: Pubkey) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::AcceptAdmin,
+            vec![
+                AccountMeta::new_readonly(wallet_pubkey, true),
+                AccountMeta::new(config, false),
+            ],
+        )
+    }
+
+    pub fn set_pause_flags(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        paused: PauseFlags,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::SetPauseFlags { paused },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new(config, false),
+            ],
+        )
+    }
+
+    pub fn create_pool(
+        wallet_pubkey: Pubkey,
+        reward_mint: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        schedule: RewardSchedule,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (stats, _) =
+            Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::CreatePool { pool_id, schedule },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(pool, false),
+                AccountMeta::new_readonly(reward_mint, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(
+                    "SysvarRent111111111111111111111111111111111"
+                        .parse::<Pubkey>()
+                        .unwrap(),
+                    false,
+                ),
+                AccountMeta::new(stats, false),
+            ],
+        )
+    }
+
+    pub fn update_reward_schedule(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        schedule: RewardSchedule,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
 
         Instruction::new_with_borsh(
             program_id,
-            &PlatformInstruction::GenerateVault,
+            &PlatformInstruction::UpdateRewardSchedule { pool_id, schedule },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(pool, false),
+            ],
+        )
+    }
+
+    pub fn set_boost_root(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        root: Option<[u8; 32]>,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::SetBoostRoot { pool_id, root },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(pool, false),
+            ],
+        )
+    }
+
+    pub fn set_pool_options(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        options: PoolOptions,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::SetPoolOptions { pool_id, options },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(pool, false),
+            ],
+        )
+    }
+
+    pub fn set_lock_tiers(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        tiers: Vec<LockTier>,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::SetLockTiers { pool_id, tiers },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(pool, false),
+            ],
+        )
+    }
+
+    pub fn deposit_rewards(
+        wallet_pubkey: Pubkey,
+        reward_mint: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        amount: u64,
+    ) -> Instruction {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (vault, _vault_bump) =
+            Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+        let source = spl_associated_token_account::get_associated_token_address(
+            &wallet_pubkey,
+            &reward_mint,
+        );
+
+        let destination =
+            spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::DepositRewards { pool_id, amount },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(vault, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(reward_mint, false),
+                AccountMeta::new(pool, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(
+                    "SysvarRent111111111111111111111111111111111"
+                        .parse::<Pubkey>()
+                        .unwrap(),
+                    false,
+                ),
+                AccountMeta::new_readonly(
+                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+                        .parse::<Pubkey>()
+                        .unwrap(),
+                    false,
+                ),
+            ],
+        )
+    }
+
+    pub fn withdraw_surplus(
+        wallet_pubkey: Pubkey,
+        reward_mint: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        amount: u64,
+    ) -> Instruction {
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (vault, _vault_bump) =
+            Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+        let source =
+            spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
+
+        let destination = spl_associated_token_account::get_associated_token_address(
+            &wallet_pubkey,
+            &reward_mint,
+        );
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::WithdrawSurplus { pool_id, amount },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(pool, false),
+                AccountMeta::new_readonly(vault, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(reward_mint, false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(
+                    "SysvarRent111111111111111111111111111111111"
+                        .parse::<Pubkey>()
+                        .unwrap(),
+                    false,
+                ),
+                AccountMeta::new_readonly(
+                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+                        .parse::<Pubkey>()
+                        .unwrap(),
+                    false,
+                ),
+            ],
+        )
+    }
+
+    pub fn generate_vault(wallet_pubkey: Pubkey, program_id: Pubkey, pool_id: u64) -> Instruction {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (vault_pda, _) =
+            Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::GenerateVault { pool_id },
             vec![
                 AccountMeta::new(wallet_pubkey, true),
                 AccountMeta::new(system_program::id(), false),
@@ -30,6 +465,8 @@ impl PlatformInstruction {
                         .unwrap(),
                     false,
                 ),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new_readonly(pool, false),
             ],
         )
     }
@@ -38,15 +475,31 @@ impl PlatformInstruction {
         wallet_pubkey: Pubkey,
         creator: Pubkey,
         program_id: Pubkey,
+        pool_id: u64,
+        eligibility: Eligibility,
+        reward_multiplier: u64,
     ) -> Instruction {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
         let (wl_address, _) = Pubkey::find_program_address(
-            &["whitelist".as_bytes(), &creator.to_bytes()],
+            &[
+                "whitelist".as_bytes(),
+                &pool.to_bytes(),
+                &creator.to_bytes(),
+            ],
             &program_id,
         );
 
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
         Instruction::new_with_borsh(
             program_id,
-            &PlatformInstruction::AddToWhitelist,
+            &PlatformInstruction::AddToWhitelist {
+                pool_id,
+                eligibility,
+                reward_multiplier,
+            },
             vec![
                 AccountMeta::new(wallet_pubkey, true),
                 AccountMeta::new(creator, false),
@@ -58,50 +511,156 @@ impl PlatformInstruction {
                         .unwrap(),
                     false,
                 ),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new_readonly(pool, false),
+            ],
+        )
+    }
+
+    pub fn remove_from_whitelist(
+        wallet_pubkey: Pubkey,
+        creator: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+    ) -> Instruction {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (wl_address, _) = Pubkey::find_program_address(
+            &[
+                "whitelist".as_bytes(),
+                &pool.to_bytes(),
+                &creator.to_bytes(),
+            ],
+            &program_id,
+        );
+
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::RemoveFromWhitelist { pool_id },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(creator, false),
+                AccountMeta::new(wl_address, false),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new_readonly(pool, false),
+            ],
+        )
+    }
+
+    pub fn update_whitelist(
+        wallet_pubkey: Pubkey,
+        creator: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_multiplier: u64,
+        enabled: bool,
+    ) -> Instruction {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (wl_address, _) = Pubkey::find_program_address(
+            &[
+                "whitelist".as_bytes(),
+                &pool.to_bytes(),
+                &creator.to_bytes(),
+            ],
+            &program_id,
+        );
+
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::UpdateWhitelist {
+                pool_id,
+                reward_multiplier,
+                enabled,
+            },
+            vec![
+                AccountMeta::new(wallet_pubkey, true),
+                AccountMeta::new_readonly(creator, false),
+                AccountMeta::new(wl_address, false),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new_readonly(pool, false),
             ],
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn stake(
         wallet_pubkey: Pubkey,
         nft: Pubkey,
         program_id: Pubkey,
+        pool_id: u64,
         metadata: Pubkey,
         metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        boost: Option<Boost>,
+        lock_tier: Option<u8>,
+        stakes: u64,
     ) -> Instruction {
-        let (vault, _vault_bump) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (vault, _vault_bump) =
+            Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
 
         let source =
             spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &nft);
 
         let destination = spl_associated_token_account::get_associated_token_address(&vault, &nft);
 
-        let (stake_data, _) = Pubkey::find_program_address(&[&nft.to_bytes()], &program_id);
+        let (stake_data, _) =
+            Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &program_id);
 
-        let metadata_data_struct: spl_token_metadata::state::Metadata =
-            try_from_slice_unchecked(&metadata_data[..]).unwrap();
-
-        let creator = metadata_data_struct
-            .data
-            .creators
-            .unwrap()
-            .first()
-            .unwrap()
-            .address;
+        let whitelist_key = get_whitelist_key(&metadata_data, eligibility).unwrap();
 
         let (wl_data_address, _wl_data_address_bump) = Pubkey::find_program_address(
-            &["whitelist".as_bytes(), &creator.to_bytes()],
+            &[
+                "whitelist".as_bytes(),
+                &pool.to_bytes(),
+                &whitelist_key.to_bytes(),
+            ],
+            &program_id,
+        );
+
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (stats, _) =
+            Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+        let (staker, _) = Pubkey::find_program_address(
+            &[
+                "staker".as_bytes(),
+                &pool.to_bytes(),
+                &wallet_pubkey.to_bytes(),
+            ],
             &program_id,
         );
 
+        // the new position gets the next receipt mint
+        let (receipt, _) = get_receipt_address(&program_id, &pool, &nft, stakes + 1);
+
+        let receipt_holder =
+            spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &receipt);
+
         Instruction::new_with_borsh(
             program_id,
-            &PlatformInstruction::Stake,
+            &PlatformInstruction::Stake {
+                pool_id,
+                eligibility,
+                boost,
+                lock_tier,
+            },
             vec![
                 AccountMeta::new(wallet_pubkey, true),
                 AccountMeta::new_readonly(nft, false),
                 AccountMeta::new_readonly(metadata, false),
-                AccountMeta::new_readonly(vault, false),
+                // token-metadata takes the delegate of soft stakes as writable
+                AccountMeta::new(vault, false),
                 AccountMeta::new(source, false),
                 AccountMeta::new(destination, false),
                 AccountMeta::new_readonly(spl_token::id(), false),
@@ -120,24 +679,267 @@ impl PlatformInstruction {
                 ),
                 AccountMeta::new(stake_data, false),
                 AccountMeta::new(wl_data_address, false),
+                AccountMeta::new(pool, false),
+                AccountMeta::new_readonly(config, false),
+                AccountMeta::new(stats, false),
+                AccountMeta::new(staker, false),
+                AccountMeta::new_readonly(get_edition_address(&nft), false),
+                AccountMeta::new_readonly(spl_token_metadata::ID, false),
+                AccountMeta::new(receipt, false),
+                AccountMeta::new(receipt_holder, false),
             ],
         )
     }
 
+    /// See `PlatformInstruction::Unstake`, the reward the vault can't pay is owed to the staker account
+    #[allow(clippy::too_many_arguments)]
     pub fn unstake(
         wallet_pubkey: Pubkey,
         nft: Pubkey,
         program_id: Pubkey,
+        pool_id: u64,
         reward_mint: Pubkey,
         metadata: Pubkey,
         metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        stakes: u64,
     ) -> Instruction {
-        let (vault, _vault_bump) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::Unstake { pool_id },
+            PlatformInstruction::claim_accounts(
+                wallet_pubkey,
+                nft,
+                program_id,
+                pool_id,
+                reward_mint,
+                metadata,
+                metadata_data,
+                eligibility,
+                stakes,
+            ),
+        )
+    }
 
-        let destination =
-            spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &nft);
+    /// Same as `unstake`, the reward goes to the `recipient` token account of the reward mint
+    #[allow(clippy::too_many_arguments)]
+    pub fn unstake_to(
+        wallet_pubkey: Pubkey,
+        nft: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        metadata: Pubkey,
+        metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        recipient: Pubkey,
+        stakes: u64,
+    ) -> Instruction {
+        let mut accounts = PlatformInstruction::claim_accounts(
+            wallet_pubkey,
+            nft,
+            program_id,
+            pool_id,
+            reward_mint,
+            metadata,
+            metadata_data,
+            eligibility,
+            stakes,
+        );
+        accounts.push(AccountMeta::new(recipient, false));
 
-        let source = spl_associated_token_account::get_associated_token_address(&vault, &nft);
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::UnstakeTo { pool_id },
+            accounts,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn request_unstake(
+        wallet_pubkey: Pubkey,
+        nft: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        metadata: Pubkey,
+        metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        stakes: u64,
+    ) -> Instruction {
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::RequestUnstake { pool_id },
+            PlatformInstruction::claim_accounts(
+                wallet_pubkey,
+                nft,
+                program_id,
+                pool_id,
+                reward_mint,
+                metadata,
+                metadata_data,
+                eligibility,
+                stakes,
+            ),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn complete_unstake(
+        wallet_pubkey: Pubkey,
+        nft: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        metadata: Pubkey,
+        metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        stakes: u64,
+    ) -> Instruction {
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::CompleteUnstake { pool_id },
+            PlatformInstruction::claim_accounts(
+                wallet_pubkey,
+                nft,
+                program_id,
+                pool_id,
+                reward_mint,
+                metadata,
+                metadata_data,
+                eligibility,
+                stakes,
+            ),
+        )
+    }
+
+    /// Claims one position, what unstakes left owed to the wallet needs `claim_all`
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim(
+        wallet_pubkey: Pubkey,
+        nft: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        metadata: Pubkey,
+        metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        stakes: u64,
+    ) -> Instruction {
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::Claim { pool_id },
+            PlatformInstruction::claim_accounts(
+                wallet_pubkey,
+                nft,
+                program_id,
+                pool_id,
+                reward_mint,
+                metadata,
+                metadata_data,
+                eligibility,
+                stakes,
+            ),
+        )
+    }
+
+    /// Hands the position of `nft` over to `new_staker`, whose signature is optional
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_position(
+        wallet_pubkey: Pubkey,
+        nft: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        metadata: Pubkey,
+        metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        new_staker: Pubkey,
+        new_staker_signs: bool,
+        stakes: u64,
+    ) -> Instruction {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (new_staker_data, _) = Pubkey::find_program_address(
+            &[
+                "staker".as_bytes(),
+                &pool.to_bytes(),
+                &new_staker.to_bytes(),
+            ],
+            &program_id,
+        );
+
+        let mut accounts = PlatformInstruction::claim_accounts(
+            wallet_pubkey,
+            nft,
+            program_id,
+            pool_id,
+            reward_mint,
+            metadata,
+            metadata_data,
+            eligibility,
+            stakes,
+        );
+        accounts.push(AccountMeta::new_readonly(new_staker, new_staker_signs));
+        accounts.push(AccountMeta::new(new_staker_data, false));
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::TransferPosition { pool_id },
+            accounts,
+        )
+    }
+
+    /// Same as `claim`, the reward goes to the `recipient` token account of the reward mint
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_to(
+        wallet_pubkey: Pubkey,
+        nft: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        metadata: Pubkey,
+        metadata_data: Vec<u8>,
+        eligibility: Eligibility,
+        recipient: Pubkey,
+        stakes: u64,
+    ) -> Instruction {
+        let mut accounts = PlatformInstruction::claim_accounts(
+            wallet_pubkey,
+            nft,
+            program_id,
+            pool_id,
+            reward_mint,
+            metadata,
+            metadata_data,
+            eligibility,
+            stakes,
+        );
+        accounts.push(AccountMeta::new(recipient, false));
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::ClaimTo { pool_id },
+            accounts,
+        )
+    }
+
+    /// Claims the rewards of all `nfts` of the wallet, one transfer per `ClaimAll` instruction,
+    /// each fits in a transaction of its own, the first also pays the reward owed to the wallet
+    /// by unstaked positions
+    pub fn claim_all(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        nfts: &[Pubkey],
+    ) -> Result<Vec<Instruction>, ProgramError> {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (vault, _vault_bump) =
+            Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
 
         let reward_destination = spl_associated_token_account::get_associated_token_address(
             &wallet_pubkey,
@@ -147,66 +949,327 @@ impl PlatformInstruction {
         let reward_source =
             spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
 
-        let (stake_data, _) = Pubkey::find_program_address(&[&nft.to_bytes()], &program_id);
-
-        let metadata_data_struct: spl_token_metadata::state::Metadata =
-            try_from_slice_unchecked(&metadata_data[..]).unwrap();
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
 
-        let creator = metadata_data_struct
-            .data
-            .creators
-            .unwrap()
-            .first()
-            .unwrap()
-            .address;
+        let (stats, _) =
+            Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
 
-        let (wl_data_address, _wl_data_address_bump) = Pubkey::find_program_address(
-            &["whitelist".as_bytes(), &creator.to_bytes()],
+        let (staker, _) = Pubkey::find_program_address(
+            &[
+                "staker".as_bytes(),
+                &pool.to_bytes(),
+                &wallet_pubkey.to_bytes(),
+            ],
             &program_id,
         );
 
-        Instruction::new_with_borsh(
-            program_id,
-            &PlatformInstruction::Unstake,
-            vec![
-                AccountMeta::new(wallet_pubkey, true),
-                AccountMeta::new_readonly(system_program::id(), false),
-                AccountMeta::new_readonly(nft, false),
-                AccountMeta::new_readonly(spl_token::id(), false),
-                AccountMeta::new_readonly(
-                    "SysvarRent111111111111111111111111111111111"
-                        .parse::<Pubkey>()
-                        .unwrap(),
-                    false,
+        let accounts = vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(
+                "SysvarRent111111111111111111111111111111111"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+                false,
+            ),
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(reward_destination, false),
+            AccountMeta::new(reward_source, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(stats, false),
+            AccountMeta::new(staker, false),
+        ];
+
+        let build = |nfts: &[Pubkey]| {
+            let mut accounts = accounts.clone();
+
+            for nft in nfts {
+                let (stake_data, _) =
+                    Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &program_id);
+
+                accounts.push(AccountMeta::new(stake_data, false));
+            }
+
+            Instruction::new_with_borsh(
+                program_id,
+                &PlatformInstruction::ClaimAll { pool_id },
+                accounts,
+            )
+        };
+
+        // a wallet without positions left can still collect what it is owed
+        if nfts.is_empty() {
+            return Ok(vec![build(nfts)]);
+        }
+
+        PlatformInstruction::pack_batch(&wallet_pubkey, nfts, build)
+    }
+
+    /// Packs `nfts` into as few `StakeBatch` instructions as possible, each fits in a transaction of its own
+    pub fn stake_batch(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        nfts: &[BatchNft],
+    ) -> Result<Vec<Instruction>, ProgramError> {
+        PlatformInstruction::pack_batch(&wallet_pubkey, nfts, |nfts| {
+            let lists: Vec<Vec<AccountMeta>> = nfts
+                .iter()
+                .map(|nft| {
+                    let mut accounts = PlatformInstruction::stake(
+                        wallet_pubkey,
+                        nft.nft,
+                        program_id,
+                        pool_id,
+                        nft.metadata,
+                        nft.metadata_data.clone(),
+                        nft.eligibility,
+                        nft.boost.clone(),
+                        nft.lock_tier,
+                        nft.stakes,
+                    )
+                    .accounts;
+                    if !nft.frozen {
+                        accounts[16] = unused_account();
+                    }
+                    if !nft.receipt {
+                        accounts[18] = unused_account();
+                        accounts[19] = unused_account();
+                    }
+                    accounts
+                })
+                .collect();
+
+            let entries = nfts
+                .iter()
+                .map(|nft| StakeEntry {
+                    eligibility: nft.eligibility,
+                    boost: nft.boost.clone(),
+                    lock_tier: nft.lock_tier,
+                })
+                .collect();
+
+            Instruction::new_with_borsh(
+                program_id,
+                &PlatformInstruction::StakeBatch { pool_id, entries },
+                join_batch(&lists, &STAKE_GROUP),
+            )
+        })
+    }
+
+    /// Packs `nfts` into as few `UnstakeBatch` instructions as possible, each fits in a transaction of its own
+    pub fn unstake_batch(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        nfts: &[BatchNft],
+    ) -> Result<Vec<Instruction>, ProgramError> {
+        PlatformInstruction::pack_batch(&wallet_pubkey, nfts, |nfts| {
+            Instruction::new_with_borsh(
+                program_id,
+                &PlatformInstruction::UnstakeBatch {
+                    pool_id,
+                    count: nfts.len() as u8,
+                },
+                PlatformInstruction::claim_batch_accounts(
+                    wallet_pubkey,
+                    program_id,
+                    pool_id,
+                    reward_mint,
+                    nfts,
                 ),
-                AccountMeta::new_readonly(
-                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-                        .parse::<Pubkey>()
-                        .unwrap(),
-                    false,
+            )
+        })
+    }
+
+    /// Packs `nfts` into as few `ClaimBatch` instructions as possible, each fits in a transaction of its own
+    pub fn claim_batch(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        nfts: &[BatchNft],
+    ) -> Result<Vec<Instruction>, ProgramError> {
+        PlatformInstruction::pack_batch(&wallet_pubkey, nfts, |nfts| {
+            Instruction::new_with_borsh(
+                program_id,
+                &PlatformInstruction::ClaimBatch {
+                    pool_id,
+                    count: nfts.len() as u8,
+                },
+                PlatformInstruction::claim_batch_accounts(
+                    wallet_pubkey,
+                    program_id,
+                    pool_id,
+                    reward_mint,
+                    nfts,
                 ),
-                AccountMeta::new(stake_data, false),
-                AccountMeta::new_readonly(vault, false),
-                AccountMeta::new(reward_destination, false),
-                AccountMeta::new(reward_source, false),
-                AccountMeta::new(destination, false),
-                AccountMeta::new(source, false),
-                AccountMeta::new_readonly(metadata, false),
-                AccountMeta::new(wl_data_address, false),
-                AccountMeta::new_readonly(reward_mint, false),
-            ],
-        )
+            )
+        })
     }
 
-    pub fn claim(
+    /// Packs `nfts` into as few `ForceUnstake` instructions as possible, each returns its NFTs to their `owner`,
+    /// the holder of the receipt for receipt positions. The owner's reward holder is only passed with `settle`
+    pub fn force_unstake(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        settle: bool,
+        nfts: &[BatchNft],
+    ) -> Result<Vec<Instruction>, ProgramError> {
+        PlatformInstruction::pack_batch(&wallet_pubkey, nfts, |nfts| {
+            let lists: Vec<Vec<AccountMeta>> = nfts
+                .iter()
+                .map(|nft| {
+                    let owner = nft.owner.expect("owner of a forced unstake");
+
+                    let mut accounts = PlatformInstruction::claim_accounts(
+                        owner,
+                        nft.nft,
+                        program_id,
+                        pool_id,
+                        reward_mint,
+                        nft.metadata,
+                        nft.metadata_data.clone(),
+                        nft.eligibility,
+                        nft.stakes,
+                    );
+                    accounts[0] = AccountMeta::new(wallet_pubkey, true);
+                    accounts[12] = unused_account();
+                    accounts[13] = unused_account();
+                    if !settle {
+                        accounts[8] = unused_account();
+                    }
+                    if !nft.frozen {
+                        accounts[19] = unused_account();
+                    }
+                    if !nft.receipt {
+                        accounts[21] = unused_account();
+                        accounts[22] = unused_account();
+                    }
+                    accounts.push(AccountMeta::new(owner, false));
+                    accounts
+                })
+                .collect();
+
+            Instruction::new_with_borsh(
+                program_id,
+                &PlatformInstruction::ForceUnstake {
+                    pool_id,
+                    count: nfts.len() as u8,
+                    settle,
+                },
+                join_batch(&lists, &FORCE_UNSTAKE_GROUP),
+            )
+        })
+    }
+
+    fn claim_batch_accounts(
+        wallet_pubkey: Pubkey,
+        program_id: Pubkey,
+        pool_id: u64,
+        reward_mint: Pubkey,
+        nfts: &[BatchNft],
+    ) -> Vec<AccountMeta> {
+        let lists: Vec<Vec<AccountMeta>> = nfts
+            .iter()
+            .map(|nft| {
+                let mut accounts = PlatformInstruction::claim_accounts(
+                    wallet_pubkey,
+                    nft.nft,
+                    program_id,
+                    pool_id,
+                    reward_mint,
+                    nft.metadata,
+                    nft.metadata_data.clone(),
+                    nft.eligibility,
+                    nft.stakes,
+                );
+                if !nft.frozen {
+                    accounts[19] = unused_account();
+                }
+                if !nft.receipt {
+                    accounts[21] = unused_account();
+                    accounts[22] = unused_account();
+                }
+                accounts
+            })
+            .collect();
+
+        join_batch(&lists, &CLAIM_GROUP)
+    }
+
+    /// Greedily grows each batch while its transaction, signed by `wallet_pubkey` only, still fits in a packet,
+    /// an NFT that doesn't fit on its own fails the whole batch
+    fn pack_batch<T, F>(
+        wallet_pubkey: &Pubkey,
+        nfts: &[T],
+        build: F,
+    ) -> Result<Vec<Instruction>, ProgramError>
+    where
+        F: Fn(&[T]) -> Instruction,
+    {
+        let fits = |instruction: Instruction| {
+            let message = Message::new(&[instruction], Some(wallet_pubkey));
+
+            // signature count and the payer signature
+            1 + 64 + message.serialize().len() <= PACKET_DATA_SIZE
+        };
+
+        let mut instructions = vec![];
+        let mut start = 0;
+
+        while start < nfts.len() {
+            let mut end = start + 1;
+
+            if !fits(build(&nfts[start..end])) {
+                return Err(ContractError::InvalidInstructionData.into());
+            }
+
+            while end < nfts.len()
+                && end - start < u8::MAX as usize
+                && fits(build(&nfts[start..=end]))
+            {
+                end += 1;
+            }
+
+            instructions.push(build(&nfts[start..end]));
+            start = end;
+        }
+
+        Ok(instructions)
+    }
+
+    /// `Claim`, `Unstake`, `RequestUnstake` and `CompleteUnstake` share the same account list,
+    /// `stakes` picks the receipt mint of the position
+    #[allow(clippy::too_many_arguments)]
+    fn claim_accounts(
         wallet_pubkey: Pubkey,
         nft: Pubkey,
         program_id: Pubkey,
+        pool_id: u64,
         reward_mint: Pubkey,
         metadata: Pubkey,
         metadata_data: Vec<u8>,
-    ) -> Instruction {
-        let (vault, _vault_bump) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
+        eligibility: Eligibility,
+        stakes: u64,
+    ) -> Vec<AccountMeta> {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+        let (vault, _vault_bump) =
+            Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
 
         let destination =
             spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &nft);
@@ -221,54 +1284,74 @@ impl PlatformInstruction {
         let reward_source =
             spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
 
-        let (stake_data, _) = Pubkey::find_program_address(&[&nft.to_bytes()], &program_id);
+        let (stake_data, _) =
+            Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &program_id);
 
-        let metadata_data_struct: spl_token_metadata::state::Metadata =
-            try_from_slice_unchecked(&metadata_data[..]).unwrap();
-
-        let creator = metadata_data_struct
-            .data
-            .creators
-            .unwrap()
-            .first()
-            .unwrap()
-            .address;
+        let whitelist_key = get_whitelist_key(&metadata_data, eligibility).unwrap();
 
         let (wl_data_address, _wl_data_address_bump) = Pubkey::find_program_address(
-            &["whitelist".as_bytes(), &creator.to_bytes()],
+            &[
+                "whitelist".as_bytes(),
+                &pool.to_bytes(),
+                &whitelist_key.to_bytes(),
+            ],
             &program_id,
         );
 
-        Instruction::new_with_borsh(
-            program_id,
-            &PlatformInstruction::Claim,
-            vec![
-                AccountMeta::new(wallet_pubkey, true),
-                AccountMeta::new_readonly(system_program::id(), false),
-                AccountMeta::new_readonly(nft, false),
-                AccountMeta::new_readonly(spl_token::id(), false),
-                AccountMeta::new_readonly(
-                    "SysvarRent111111111111111111111111111111111"
-                        .parse::<Pubkey>()
-                        .unwrap(),
-                    false,
-                ),
-                AccountMeta::new_readonly(
-                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-                        .parse::<Pubkey>()
-                        .unwrap(),
-                    false,
-                ),
-                AccountMeta::new(stake_data, false),
-                AccountMeta::new_readonly(vault, false),
-                AccountMeta::new(reward_destination, false),
-                AccountMeta::new(reward_source, false),
-                AccountMeta::new(destination, false),
-                AccountMeta::new(source, false),
-                AccountMeta::new_readonly(metadata, false),
-                AccountMeta::new(wl_data_address, false),
-                AccountMeta::new_readonly(reward_mint, false),
+        let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+        let (stats, _) =
+            Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+        let (staker, _) = Pubkey::find_program_address(
+            &[
+                "staker".as_bytes(),
+                &pool.to_bytes(),
+                &wallet_pubkey.to_bytes(),
             ],
-        )
+            &program_id,
+        );
+
+        let (receipt, _) = get_receipt_address(&program_id, &pool, &nft, stakes);
+
+        let receipt_holder =
+            spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &receipt);
+
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(nft, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(
+                "SysvarRent111111111111111111111111111111111"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+                false,
+            ),
+            AccountMeta::new(stake_data, false),
+            // token-metadata takes the delegate of soft stakes as writable
+            AccountMeta::new(vault, false),
+            AccountMeta::new(reward_destination, false),
+            AccountMeta::new(reward_source, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new(source, false),
+            AccountMeta::new_readonly(metadata, false),
+            AccountMeta::new(wl_data_address, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(stats, false),
+            AccountMeta::new(staker, false),
+            AccountMeta::new_readonly(get_edition_address(&nft), false),
+            AccountMeta::new_readonly(spl_token_metadata::ID, false),
+            AccountMeta::new(receipt, false),
+            AccountMeta::new(receipt_holder, false),
+        ]
     }
 }
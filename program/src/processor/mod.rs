@@ -1,12 +1,34 @@
+pub mod platform;
 pub mod staking;
 
 use crate::error::ContractError;
 use crate::instruction::PlatformInstruction;
+use crate::processor::platform::accept_admin::accept_admin;
+use crate::processor::platform::initialize_platform::initialize_platform;
+use crate::processor::platform::propose_admin::propose_admin;
+use crate::processor::platform::set_pause_flags::set_pause_flags;
 use crate::processor::staking::add_to_whitelist::add_to_whitelist;
-use crate::processor::staking::claim::claim;
+use crate::processor::staking::claim::{claim, claim_to};
+use crate::processor::staking::claim_all::claim_all;
+use crate::processor::staking::claim_batch::claim_batch;
+use crate::processor::staking::complete_unstake::complete_unstake;
+use crate::processor::staking::create_pool::create_pool;
+use crate::processor::staking::deposit_rewards::deposit_rewards;
+use crate::processor::staking::force_unstake::force_unstake;
 use crate::processor::staking::generate_vault::generate_vault;
+use crate::processor::staking::remove_from_whitelist::remove_from_whitelist;
+use crate::processor::staking::set_boost_root::set_boost_root;
+use crate::processor::staking::set_lock_tiers::set_lock_tiers;
+use crate::processor::staking::set_pool_options::set_pool_options;
 use crate::processor::staking::stake::stake;
-use crate::processor::staking::unstake::unstake;
+use crate::processor::staking::stake_batch::stake_batch;
+use crate::processor::staking::transfer_position::transfer_position;
+use crate::processor::staking::unstake::{request_unstake, unstake, unstake_to};
+use crate::processor::staking::unstake_batch::unstake_batch;
+use crate::processor::staking::update_reward_schedule::update_reward_schedule;
+use crate::processor::staking::update_whitelist::update_whitelist;
+use crate::processor::staking::withdraw_surplus::withdraw_surplus;
+use crate::state::config::check_not_paused;
 use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
@@ -32,15 +54,141 @@ impl Processor {
             };
 
         match instruction {
-            PlatformInstruction::GenerateVault => generate_vault(accounts, program_id)?,
+            PlatformInstruction::Stake { .. } | PlatformInstruction::StakeBatch { .. } => {
+                check_not_paused(program_id, accounts, |paused| paused.stake)?
+            }
+            PlatformInstruction::Unstake { .. }
+            | PlatformInstruction::UnstakeBatch { .. }
+            | PlatformInstruction::UnstakeTo { .. }
+            | PlatformInstruction::RequestUnstake { .. }
+            | PlatformInstruction::CompleteUnstake { .. }
+            | PlatformInstruction::TransferPosition { .. } => {
+                check_not_paused(program_id, accounts, |paused| paused.unstake)?
+            }
+            PlatformInstruction::Claim { .. }
+            | PlatformInstruction::ClaimBatch { .. }
+            | PlatformInstruction::ClaimAll { .. }
+            | PlatformInstruction::ClaimTo { .. } => {
+                check_not_paused(program_id, accounts, |paused| paused.claim)?
+            }
+            _ => {}
+        }
 
-            PlatformInstruction::AddToWhitelist => add_to_whitelist(accounts, program_id)?,
+        match instruction {
+            PlatformInstruction::GenerateVault { pool_id } => {
+                generate_vault(accounts, program_id, pool_id)?
+            }
+
+            PlatformInstruction::AddToWhitelist {
+                pool_id,
+                eligibility,
+                reward_multiplier,
+            } => add_to_whitelist(
+                accounts,
+                program_id,
+                pool_id,
+                eligibility,
+                reward_multiplier,
+            )?,
+
+            PlatformInstruction::Stake {
+                pool_id,
+                eligibility,
+                boost,
+                lock_tier,
+            } => stake(accounts, program_id, pool_id, eligibility, boost, lock_tier)?,
+
+            PlatformInstruction::Unstake { pool_id } => unstake(accounts, program_id, pool_id)?,
+
+            PlatformInstruction::Claim { pool_id } => claim(accounts, program_id, pool_id)?,
+
+            PlatformInstruction::InitializePlatform => initialize_platform(accounts, program_id)?,
+
+            PlatformInstruction::ProposeAdmin { new_admin } => {
+                propose_admin(accounts, program_id, new_admin)?
+            }
+
+            PlatformInstruction::AcceptAdmin => accept_admin(accounts, program_id)?,
+
+            PlatformInstruction::CreatePool { pool_id, schedule } => {
+                create_pool(accounts, program_id, pool_id, schedule)?
+            }
+
+            PlatformInstruction::UpdateRewardSchedule { pool_id, schedule } => {
+                update_reward_schedule(accounts, program_id, pool_id, schedule)?
+            }
+
+            PlatformInstruction::SetPauseFlags { paused } => {
+                set_pause_flags(accounts, program_id, paused)?
+            }
+
+            PlatformInstruction::RemoveFromWhitelist { pool_id } => {
+                remove_from_whitelist(accounts, program_id, pool_id)?
+            }
+
+            PlatformInstruction::UpdateWhitelist {
+                pool_id,
+                reward_multiplier,
+                enabled,
+            } => update_whitelist(accounts, program_id, pool_id, reward_multiplier, enabled)?,
+
+            PlatformInstruction::SetBoostRoot { pool_id, root } => {
+                set_boost_root(accounts, program_id, pool_id, root)?
+            }
+
+            PlatformInstruction::DepositRewards { pool_id, amount } => {
+                deposit_rewards(accounts, program_id, pool_id, amount)?
+            }
+
+            PlatformInstruction::SetPoolOptions { pool_id, options } => {
+                set_pool_options(accounts, program_id, pool_id, options)?
+            }
+
+            PlatformInstruction::WithdrawSurplus { pool_id, amount } => {
+                withdraw_surplus(accounts, program_id, pool_id, amount)?
+            }
+
+            PlatformInstruction::StakeBatch { pool_id, entries } => {
+                stake_batch(accounts, program_id, pool_id, entries)?
+            }
+
+            PlatformInstruction::UnstakeBatch { pool_id, count } => {
+                unstake_batch(accounts, program_id, pool_id, count)?
+            }
+
+            PlatformInstruction::ClaimBatch { pool_id, count } => {
+                claim_batch(accounts, program_id, pool_id, count)?
+            }
+
+            PlatformInstruction::ClaimAll { pool_id } => claim_all(accounts, program_id, pool_id)?,
+
+            PlatformInstruction::SetLockTiers { pool_id, tiers } => {
+                set_lock_tiers(accounts, program_id, pool_id, tiers)?
+            }
+
+            PlatformInstruction::RequestUnstake { pool_id } => {
+                request_unstake(accounts, program_id, pool_id)?
+            }
+
+            PlatformInstruction::CompleteUnstake { pool_id } => {
+                complete_unstake(accounts, program_id, pool_id)?
+            }
+
+            PlatformInstruction::ClaimTo { pool_id } => claim_to(accounts, program_id, pool_id)?,
 
-            PlatformInstruction::Stake => stake(accounts, program_id)?,
+            PlatformInstruction::UnstakeTo { pool_id } => {
+                unstake_to(accounts, program_id, pool_id)?
+            }
 
-            PlatformInstruction::Unstake => unstake(accounts, program_id)?,
+            PlatformInstruction::TransferPosition { pool_id } => {
+                transfer_position(accounts, program_id, pool_id)?
+            }
 
-            PlatformInstruction::Claim => claim(accounts, program_id)?,
+            PlatformInstruction::ForceUnstake {
+                pool_id,
+                count,
+                settle,
+            } => force_unstake(accounts, program_id, pool_id, count, settle)?,
         };
 
         Ok(())
@@ -0,0 +1,46 @@
+use crate::error::ContractError;
+use crate::state::config::get_platform_config;
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn accept_admin(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let mut config = get_platform_config(program_id, accounts.config_info)?;
+
+    let pending_admin = match config.pending_admin {
+        Some(pending_admin) => pending_admin,
+        None => return Err(ContractError::NoPendingAdmin.into()),
+    };
+
+    if *accounts.payer.key != pending_admin || !accounts.payer.is_signer {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    config.admin = pending_admin;
+    config.pending_admin = None;
+    config.serialize(&mut &mut accounts.config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use crate::consts::CONFIG;
+use crate::error::ContractError;
+use crate::state::config::check_upgrade_authority;
+use crate::state::pda::create_pda_account;
+use crate::state::structs::{PauseFlags, PlatformConfig};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::borsh::get_packed_len;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+pub fn initialize_platform(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let rent = &Rent::from_account_info(accounts.rent_info)?;
+
+    check_upgrade_authority(program_id, accounts.program_data_info, accounts.payer)?;
+
+    let (config_address, config_bump) = Pubkey::find_program_address(&[CONFIG], program_id);
+
+    if *accounts.config_info.key != config_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if accounts.config_info.owner == program_id {
+        return Err(ContractError::AlreadyInitialized.into());
+    }
+
+    create_pda_account(
+        accounts.payer,
+        accounts.config_info,
+        accounts.sys_info,
+        program_id,
+        rent,
+        get_packed_len::<PlatformConfig>(),
+        &[CONFIG, &[config_bump]],
+    )?;
+
+    let config = PlatformConfig {
+        admin: *accounts.payer.key,
+        pending_admin: None,
+        paused: PauseFlags::default(),
+    };
+    config.serialize(&mut &mut accounts.config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub program_data_info: &'a AccountInfo<'b>,
+    pub sys_info: &'a AccountInfo<'b>,
+    pub rent_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            program_data_info: next_account_info(acc_iter)?,
+            sys_info: next_account_info(acc_iter)?,
+            rent_info: next_account_info(acc_iter)?,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod accept_admin;
+pub mod initialize_platform;
+pub mod propose_admin;
+pub mod set_pause_flags;
@@ -0,0 +1,41 @@
+use crate::state::config::{check_admin, get_platform_config};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn propose_admin(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    new_admin: Pubkey,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let mut config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    config.pending_admin = Some(new_admin);
+    config.serialize(&mut &mut accounts.config_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+        })
+    }
+}
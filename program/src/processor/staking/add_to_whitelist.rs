@@ -1,28 +1,45 @@
-use crate::consts::{ADMIN, WHITELIST};
+use crate::consts::WHITELIST;
 use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pda::create_pda_account;
+use crate::state::pool::get_pool;
+use crate::state::structs::{Eligibility, WhitelistData};
+use crate::state::whitelist::get_whitelist_address;
+use borsh::BorshSerialize;
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::borsh::get_packed_len;
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
-use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
-use solana_program::system_instruction;
 use solana_program::sysvar::Sysvar;
 
-pub fn add_to_whitelist(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+pub fn add_to_whitelist(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    eligibility: Eligibility,
+    reward_multiplier: u64,
+) -> ProgramResult {
     let accounts = Accounts::new(accounts)?;
 
+    if reward_multiplier == 0 {
+        return Err(ContractError::InvalidMultiplier.into());
+    }
+
     let rent = &Rent::from_account_info(accounts.rent_info)?;
 
-    let admin = ADMIN.parse::<Pubkey>().unwrap();
+    let config = get_platform_config(program_id, accounts.config_info)?;
 
-    if *accounts.payer.key != admin || !accounts.payer.is_signer {
-        return Err(ContractError::UnauthorisedAccess.into());
-    }
+    check_admin(&config, accounts.payer)?;
 
-    let (data_address, data_address_bump) = Pubkey::find_program_address(
-        &[WHITELIST, &accounts.creator_info.key.to_bytes()],
+    get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let (data_address, data_address_bump) = get_whitelist_address(
         program_id,
+        accounts.pool_info.key,
+        accounts.creator_info.key,
     );
 
     if *accounts.whitelist_info.key != data_address {
@@ -30,29 +47,29 @@ pub fn add_to_whitelist(accounts: &[AccountInfo], program_id: &Pubkey) -> Progra
     }
 
     if accounts.whitelist_info.owner != program_id {
-        let required_lamports = rent
-            .minimum_balance(0)
-            .max(1)
-            .saturating_sub(accounts.whitelist_info.lamports());
-
-        invoke(
-            &system_instruction::transfer(accounts.payer.key, &data_address, required_lamports),
+        create_pda_account(
+            accounts.payer,
+            accounts.whitelist_info,
+            accounts.sys_info,
+            program_id,
+            rent,
+            get_packed_len::<WhitelistData>(),
             &[
-                accounts.payer.clone(),
-                accounts.whitelist_info.clone(),
-                accounts.sys_info.clone(),
-            ],
-        )?;
-
-        invoke_signed(
-            &system_instruction::assign(&data_address, program_id),
-            &[accounts.whitelist_info.clone(), accounts.sys_info.clone()],
-            &[&[
                 WHITELIST,
+                &accounts.pool_info.key.to_bytes(),
                 &accounts.creator_info.key.to_bytes(),
                 &[data_address_bump],
-            ]],
+            ],
         )?;
+
+        let whitelist = WhitelistData {
+            added_at: Clock::get()?.unix_timestamp as u64,
+            added_by: *accounts.payer.key,
+            reward_multiplier,
+            eligibility,
+            ..WhitelistData::default()
+        };
+        whitelist.serialize(&mut &mut accounts.whitelist_info.data.borrow_mut()[..])?;
     }
 
     Ok(())
@@ -61,10 +78,13 @@ pub fn add_to_whitelist(accounts: &[AccountInfo], program_id: &Pubkey) -> Progra
 #[allow(dead_code)]
 pub struct Accounts<'a, 'b> {
     pub payer: &'a AccountInfo<'b>,
+    /// First creator or collection mint, depending on `eligibility`
     pub creator_info: &'a AccountInfo<'b>,
     pub whitelist_info: &'a AccountInfo<'b>,
     pub sys_info: &'a AccountInfo<'b>,
     pub rent_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
 }
 
 impl<'a, 'b> Accounts<'a, 'b> {
@@ -78,6 +98,8 @@ impl<'a, 'b> Accounts<'a, 'b> {
             whitelist_info: next_account_info(acc_iter)?,
             sys_info: next_account_info(acc_iter)?,
             rent_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
         })
     }
 }
@@ -1,27 +1,46 @@
-use crate::consts::{REWARD_MINT, VAULT, WHITELIST};
 use crate::error::ContractError;
-use crate::state::claim::claim_transfer;
-use crate::state::reward_calculation::calculate_reward;
+use crate::state::claim::{claim_transfer, RewardAccounts};
+use crate::state::ledger::record_payout;
+use crate::state::pool::{check_unlocked, get_pool, get_vault_address};
+use crate::state::receipt::check_position_owner;
+use crate::state::reward_calculation::{calculate_reward, stake_multiplier};
 use crate::state::stake::get_stake_data;
+use crate::state::staker::get_staker;
+use crate::state::stats::{create_stats_account, get_stats};
 use borsh::BorshSerialize;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
-pub fn claim(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
-    let accounts = Accounts::new(accounts)?;
+pub fn claim(accounts: &[AccountInfo], program_id: &Pubkey, pool_id: u64) -> ProgramResult {
+    claim_rewards(Accounts::new(accounts)?, program_id, pool_id)
+}
+
+/// Same as `claim`, the reward is paid to the recipient token account instead of the payer's ATA
+pub fn claim_to(accounts: &[AccountInfo], program_id: &Pubkey, pool_id: u64) -> ProgramResult {
+    claim_rewards(Accounts::new_with_recipient(accounts)?, program_id, pool_id)
+}
 
+fn claim_rewards(accounts: Accounts, program_id: &Pubkey, pool_id: u64) -> ProgramResult {
     let clock = Clock::get()?;
 
-    let reward_mint = REWARD_MINT.parse::<Pubkey>().unwrap();
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
 
-    let (stake_address, _stake_bump) =
-        Pubkey::find_program_address(&[&accounts.nft_info.key.to_bytes()], program_id);
+    let reward_mint = pool.reward_mint;
+
+    let (stake_address, _stake_bump) = Pubkey::find_program_address(
+        &[
+            &accounts.pool_info.key.to_bytes(),
+            &accounts.nft_info.key.to_bytes(),
+        ],
+        program_id,
+    );
 
-    let (vault_address, vault_bump) = Pubkey::find_program_address(&[VAULT], program_id);
+    let vault_address = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
 
     let payer_reward_holder = spl_associated_token_account::get_associated_token_address(
         accounts.payer.key,
@@ -69,44 +88,67 @@ pub fn claim(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         return Err(ContractError::InvalidInstructionData.into());
     }
 
-    let metadata = spl_token_metadata::state::Metadata::from_account_info(accounts.metadata_info)?;
-    let creators = metadata.data.creators.unwrap();
-    let creator = creators.first().unwrap();
-    let creator_address = creator.address;
-
-    let (wl_data_address, _wl_data_address_bump) =
-        Pubkey::find_program_address(&[WHITELIST, &creator_address.to_bytes()], program_id);
-
-    if *accounts.whitelist_info.key != wl_data_address {
-        return Err(ContractError::InvalidInstructionData.into());
-    }
-
     let mut stake_data = get_stake_data(&accounts.stake_info.data.borrow())?;
 
-    if !creator.verified {
-        return Err(ContractError::UnverifiedAddress.into());
+    // entry the NFT was staked under, NFTs of a removed entry can still be claimed
+    if *accounts.whitelist_info.key != stake_data.whitelist {
+        return Err(ContractError::InvalidInstructionData.into());
     }
 
+    // reward left by an unstake is owed to the staker account, see `ClaimAll`
     if !stake_data.active {
         return Err(ContractError::InactiveStaking.into());
     }
 
-    if stake_data.staker != *accounts.payer.key {
-        return Err(ContractError::UnauthorisedAccess.into());
-    }
+    check_position_owner(&accounts, program_id, &stake_data)?;
 
-    let reward = calculate_reward(
-        clock.unix_timestamp as u64,
-        stake_data.timestamp,
-        stake_data.harvested,
-        stake_data.withdrawn,
-    );
+    check_unlocked(&stake_data, clock.unix_timestamp as u64)?;
+
+    let reward = stake_data.accrued
+        + calculate_reward(
+            &pool.epochs,
+            clock.unix_timestamp as u64,
+            stake_data.timestamp,
+            stake_data.checkpoint,
+            stake_data.harvested + stake_data.accrued,
+            stake_multiplier(&stake_data),
+        );
 
-    claim_transfer(&accounts, vault_bump, reward)?;
+    create_stats_account(
+        accounts.payer,
+        accounts.stats_info,
+        accounts.system_program,
+        program_id,
+        &Rent::from_account_info(accounts.rent_info)?,
+        accounts.pool_info.key,
+    )?;
+
+    let mut stats = get_stats(program_id, accounts.pool_info.key, accounts.stats_info)?;
+
+    let staker = get_staker(
+        program_id,
+        accounts.pool_info.key,
+        accounts.payer.key,
+        accounts.staker_info,
+    )?;
+
+    claim_transfer(&accounts.reward_accounts(), pool.vault_bump, reward)?;
+
+    record_payout(&mut pool.ledger, &mut stake_data, reward);
+    stats.rewards_distributed += reward;
 
     stake_data.harvested += reward;
     stake_data.withdrawn += reward;
+    stake_data.accrued = 0;
+    stake_data.checkpoint = clock.unix_timestamp as u64;
     stake_data.serialize(&mut &mut accounts.stake_info.data.borrow_mut()[..])?;
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+    stats.serialize(&mut &mut accounts.stats_info.data.borrow_mut()[..])?;
+
+    if let Some(mut staker) = staker {
+        staker.rewards_claimed += reward;
+        staker.serialize(&mut &mut accounts.staker_info.data.borrow_mut()[..])?;
+    }
 
     Ok(())
 }
@@ -128,6 +170,18 @@ pub struct Accounts<'a, 'b> {
     pub metadata_info: &'a AccountInfo<'b>,
     pub whitelist_info: &'a AccountInfo<'b>,
     pub reward_mint_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub stats_info: &'a AccountInfo<'b>,
+    pub staker_info: &'a AccountInfo<'b>,
+    pub edition_info: &'a AccountInfo<'b>,
+    pub metadata_program_info: &'a AccountInfo<'b>,
+    pub receipt_mint_info: &'a AccountInfo<'b>,
+    pub receipt_holder_info: &'a AccountInfo<'b>,
+    /// Reward token account of `ClaimTo` and `UnstakeTo`, the payer's ATA otherwise
+    pub recipient_info: Option<&'a AccountInfo<'b>>,
+    /// Wallet of the position in `ForceUnstake`, the payer otherwise
+    pub owner_info: Option<&'a AccountInfo<'b>>,
 }
 
 impl<'a, 'b> Accounts<'a, 'b> {
@@ -151,6 +205,68 @@ impl<'a, 'b> Accounts<'a, 'b> {
             metadata_info: next_account_info(acc_iter)?,
             whitelist_info: next_account_info(acc_iter)?,
             reward_mint_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            stats_info: next_account_info(acc_iter)?,
+            staker_info: next_account_info(acc_iter)?,
+            edition_info: next_account_info(acc_iter)?,
+            metadata_program_info: next_account_info(acc_iter)?,
+            receipt_mint_info: next_account_info(acc_iter)?,
+            receipt_holder_info: next_account_info(acc_iter)?,
+            recipient_info: None,
+            owner_info: None,
+        })
+    }
+
+    /// Accounts of `ClaimTo` and `UnstakeTo`, the recipient comes last
+    pub fn new_with_recipient(
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let (recipient_info, accounts) = match accounts.split_last() {
+            Some(split) => split,
+            None => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+
+        Ok(Accounts {
+            recipient_info: Some(recipient_info),
+            ..Accounts::new(accounts)?
         })
     }
+
+    /// Accounts of `ForceUnstake`, the owner comes last
+    pub fn new_with_owner(
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let (owner_info, accounts) = match accounts.split_last() {
+            Some(split) => split,
+            None => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+
+        Ok(Accounts {
+            owner_info: Some(owner_info),
+            ..Accounts::new(accounts)?
+        })
+    }
+
+    /// Wallet the NFT and the reward go back to
+    pub fn owner(&self) -> &'a AccountInfo<'b> {
+        self.owner_info.unwrap_or(self.payer)
+    }
+
+    pub fn reward_accounts(&self) -> RewardAccounts<'a, 'b> {
+        RewardAccounts {
+            payer: self.payer,
+            owner: self.owner(),
+            system_program: self.system_program,
+            token_info: self.token_info,
+            rent_info: self.rent_info,
+            assoc_acccount_info: self.assoc_acccount_info,
+            vault_info: self.vault_info,
+            payer_reward_holder_info: self.payer_reward_holder_info,
+            vault_reward_holder_info: self.vault_reward_holder_info,
+            reward_mint_info: self.reward_mint_info,
+            pool_info: self.pool_info,
+            recipient_info: self.recipient_info,
+        }
+    }
 }
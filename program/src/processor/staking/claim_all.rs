@@ -0,0 +1,212 @@
+use crate::error::ContractError;
+use crate::state::claim::{claim_transfer, RewardAccounts};
+use crate::state::ledger::{record_debt_payout, record_payout};
+use crate::state::pool::{check_unlocked, get_pool, get_vault_address};
+use crate::state::reward_calculation::{calculate_reward, stake_multiplier};
+use crate::state::stake::get_stake_data;
+use crate::state::staker::{get_staker, save_staker};
+use crate::state::stats::{create_stats_account, get_stats};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+pub fn claim_all(accounts: &[AccountInfo], program_id: &Pubkey, pool_id: u64) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let clock = Clock::get()?;
+
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let reward_mint = pool.reward_mint;
+
+    let vault_address = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
+
+    let payer_reward_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.payer.key,
+        &reward_mint,
+    );
+
+    let vault_reward_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.vault_info.key,
+        &reward_mint,
+    );
+
+    if !accounts.payer.is_signer {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    if *accounts.token_info.key != spl_token::id()
+        || vault_address != *accounts.vault_info.key
+        || payer_reward_holder != *accounts.payer_reward_holder_info.key
+        || vault_reward_holder != *accounts.vault_reward_holder_info.key
+        || reward_mint != *accounts.reward_mint_info.key
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let mut staker = get_staker(
+        program_id,
+        accounts.pool_info.key,
+        accounts.payer.key,
+        accounts.staker_info,
+    )?;
+
+    let owed = staker.as_ref().map_or(0, |staker| staker.owed);
+
+    if accounts.stake_infos.is_empty() && owed == 0 {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let mut total = owed;
+
+    for stake_info in accounts.stake_infos {
+        if stake_info.owner != program_id {
+            return Err(ContractError::InvalidInstructionData.into());
+        }
+
+        let mut stake_data = get_stake_data(&stake_info.data.borrow())?;
+
+        let (stake_address, _stake_bump) = Pubkey::find_program_address(
+            &[
+                &accounts.pool_info.key.to_bytes(),
+                &stake_data.mint.to_bytes(),
+            ],
+            program_id,
+        );
+
+        if stake_address != *stake_info.key {
+            return Err(ContractError::InvalidInstructionData.into());
+        }
+
+        if stake_data.staker != *accounts.payer.key {
+            return Err(ContractError::UnauthorisedAccess.into());
+        }
+
+        // only the holder of the receipt can claim it, with `Claim`
+        if stake_data.receipt {
+            return Err(ContractError::ReceiptPosition.into());
+        }
+
+        // reward left by an unstake is owed to the staker account, see `ClaimAll`
+        if !stake_data.active {
+            return Err(ContractError::InactiveStaking.into());
+        }
+
+        check_unlocked(&stake_data, clock.unix_timestamp as u64)?;
+
+        let reward = stake_data.accrued
+            + calculate_reward(
+                &pool.epochs,
+                clock.unix_timestamp as u64,
+                stake_data.timestamp,
+                stake_data.checkpoint,
+                stake_data.harvested + stake_data.accrued,
+                stake_multiplier(&stake_data),
+            );
+
+        record_payout(&mut pool.ledger, &mut stake_data, reward);
+
+        stake_data.harvested += reward;
+        stake_data.withdrawn += reward;
+        stake_data.accrued = 0;
+        stake_data.checkpoint = clock.unix_timestamp as u64;
+        // serialized right away, a position passed twice has nothing left the second time
+        stake_data.serialize(&mut &mut stake_info.data.borrow_mut()[..])?;
+
+        total += reward;
+    }
+
+    create_stats_account(
+        accounts.payer,
+        accounts.stats_info,
+        accounts.system_program,
+        program_id,
+        &Rent::from_account_info(accounts.rent_info)?,
+        accounts.pool_info.key,
+    )?;
+
+    let mut stats = get_stats(program_id, accounts.pool_info.key, accounts.stats_info)?;
+
+    claim_transfer(&accounts.reward_accounts(), pool.vault_bump, total)?;
+
+    stats.rewards_distributed += total;
+
+    if let Some(staker) = staker.as_mut() {
+        record_debt_payout(&mut pool.ledger, staker, owed);
+        staker.rewards_claimed += total;
+    }
+
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+    stats.serialize(&mut &mut accounts.stats_info.data.borrow_mut()[..])?;
+
+    if let Some(staker) = staker {
+        save_staker(&staker, accounts.staker_info, accounts.payer)?;
+    }
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    pub token_info: &'a AccountInfo<'b>,
+    pub rent_info: &'a AccountInfo<'b>,
+    pub assoc_acccount_info: &'a AccountInfo<'b>,
+    pub vault_info: &'a AccountInfo<'b>,
+    pub payer_reward_holder_info: &'a AccountInfo<'b>,
+    pub vault_reward_holder_info: &'a AccountInfo<'b>,
+    pub reward_mint_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub stats_info: &'a AccountInfo<'b>,
+    pub staker_info: &'a AccountInfo<'b>,
+    /// `StakeData` accounts of the payer
+    pub stake_infos: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            system_program: next_account_info(acc_iter)?,
+            token_info: next_account_info(acc_iter)?,
+            rent_info: next_account_info(acc_iter)?,
+            assoc_acccount_info: next_account_info(acc_iter)?,
+            vault_info: next_account_info(acc_iter)?,
+            payer_reward_holder_info: next_account_info(acc_iter)?,
+            vault_reward_holder_info: next_account_info(acc_iter)?,
+            reward_mint_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            stats_info: next_account_info(acc_iter)?,
+            staker_info: next_account_info(acc_iter)?,
+            stake_infos: acc_iter.as_slice(),
+        })
+    }
+
+    pub fn reward_accounts(&self) -> RewardAccounts<'a, 'b> {
+        RewardAccounts {
+            payer: self.payer,
+            owner: self.payer,
+            system_program: self.system_program,
+            token_info: self.token_info,
+            rent_info: self.rent_info,
+            assoc_acccount_info: self.assoc_acccount_info,
+            vault_info: self.vault_info,
+            payer_reward_holder_info: self.payer_reward_holder_info,
+            vault_reward_holder_info: self.vault_reward_holder_info,
+            reward_mint_info: self.reward_mint_info,
+            pool_info: self.pool_info,
+            recipient_info: None,
+        }
+    }
+}
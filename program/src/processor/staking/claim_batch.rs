@@ -0,0 +1,20 @@
+use crate::processor::staking::claim::claim;
+use crate::state::batch::{split_batch, CLAIM_ACCOUNTS, CLAIM_GROUP};
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+pub fn claim_batch(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    count: u8,
+) -> ProgramResult {
+    let batch = split_batch(accounts, CLAIM_ACCOUNTS, &CLAIM_GROUP, count as usize)?;
+
+    for accounts in batch.iter() {
+        claim(accounts, program_id, pool_id)?;
+    }
+
+    Ok(())
+}
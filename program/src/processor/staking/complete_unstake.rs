@@ -0,0 +1,90 @@
+use crate::error::ContractError;
+use crate::processor::staking::claim::Accounts;
+use crate::state::pool::{get_pool, get_vault_address};
+use crate::state::stake::get_stake_data;
+use crate::state::staker::{get_staker, remove_position, save_staker};
+use crate::state::unstake::return_nft;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+pub fn complete_unstake(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let clock = Clock::get()?;
+
+    let pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let (stake_address, _stake_bump) = Pubkey::find_program_address(
+        &[
+            &accounts.pool_info.key.to_bytes(),
+            &accounts.nft_info.key.to_bytes(),
+        ],
+        program_id,
+    );
+
+    let vault_address = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
+
+    let payer_nft_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.payer.key,
+        accounts.nft_info.key,
+    );
+
+    let vault_nft_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.vault_info.key,
+        accounts.nft_info.key,
+    );
+
+    if !accounts.payer.is_signer {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    if *accounts.token_info.key != spl_token::id()
+        || stake_address != *accounts.stake_info.key
+        || vault_address != *accounts.vault_info.key
+        || payer_nft_holder != *accounts.payer_nft_holder_info.key
+        || vault_nft_holder != *accounts.vault_nft_holder_info.key
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let mut stake_data = get_stake_data(&accounts.stake_info.data.borrow())?;
+
+    if stake_data.staker != *accounts.payer.key {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    if stake_data.unbonding_until == 0 {
+        return Err(ContractError::NotUnbonding.into());
+    }
+
+    if (clock.unix_timestamp as u64) < stake_data.unbonding_until {
+        return Err(ContractError::StillUnbonding.into());
+    }
+
+    return_nft(&accounts, &stake_data, pool.vault_bump)?;
+
+    stake_data.unbonding_until = 0;
+    stake_data.serialize(&mut &mut accounts.stake_info.data.borrow_mut()[..])?;
+
+    let staker = get_staker(
+        program_id,
+        accounts.pool_info.key,
+        accounts.payer.key,
+        accounts.staker_info,
+    )?;
+
+    if let Some(mut staker) = staker {
+        remove_position(&mut staker, &stake_data.mint);
+        save_staker(&staker, accounts.staker_info, accounts.payer)?;
+    }
+
+    Ok(())
+}
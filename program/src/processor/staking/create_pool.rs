@@ -0,0 +1,117 @@
+use crate::consts::{POOL, VAULT};
+use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pda::create_pda_account;
+use crate::state::pool::{check_schedule, pool_size};
+use crate::state::stats::create_stats_account;
+use crate::state::structs::{Pool, PoolOptions, RewardLedger, RewardSchedule, ScheduleEpoch};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+pub fn create_pool(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    schedule: RewardSchedule,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let rent = &Rent::from_account_info(accounts.rent_info)?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    let (pool_address, pool_bump) =
+        Pubkey::find_program_address(&[POOL, &pool_id.to_le_bytes()], program_id);
+
+    if *accounts.pool_info.key != pool_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if accounts.pool_info.owner == program_id {
+        return Err(ContractError::PoolAlreadyExists.into());
+    }
+
+    if *accounts.reward_mint_info.owner != spl_token::id()
+        || spl_token::state::Mint::unpack(&accounts.reward_mint_info.data.borrow()).is_err()
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    check_schedule(&schedule)?;
+
+    let (_vault_address, vault_bump) =
+        Pubkey::find_program_address(&[VAULT, &pool_address.to_bytes()], program_id);
+
+    create_pda_account(
+        accounts.payer,
+        accounts.pool_info,
+        accounts.sys_info,
+        program_id,
+        rent,
+        pool_size(),
+        &[POOL, &pool_id.to_le_bytes(), &[pool_bump]],
+    )?;
+
+    let pool = Pool {
+        pool_id,
+        reward_mint: *accounts.reward_mint_info.key,
+        vault_bump,
+        boost_root: None,
+        options: PoolOptions::default(),
+        ledger: RewardLedger::default(),
+        lock_tiers: vec![],
+        epochs: vec![ScheduleEpoch {
+            start: Clock::get()?.unix_timestamp as u64,
+            schedule,
+        }],
+    };
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+
+    create_stats_account(
+        accounts.payer,
+        accounts.stats_info,
+        accounts.sys_info,
+        program_id,
+        rent,
+        accounts.pool_info.key,
+    )?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+    pub reward_mint_info: &'a AccountInfo<'b>,
+    pub sys_info: &'a AccountInfo<'b>,
+    pub rent_info: &'a AccountInfo<'b>,
+    pub stats_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+            reward_mint_info: next_account_info(acc_iter)?,
+            sys_info: next_account_info(acc_iter)?,
+            rent_info: next_account_info(acc_iter)?,
+            stats_info: next_account_info(acc_iter)?,
+        })
+    }
+}
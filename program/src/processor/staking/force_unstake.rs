@@ -0,0 +1,224 @@
+use crate::error::ContractError;
+use crate::processor::staking::claim::Accounts;
+use crate::state::batch::{split_batch, FORCE_UNSTAKE_ACCOUNTS, FORCE_UNSTAKE_GROUP};
+use crate::state::claim::{claim_transfer, vault_balance};
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::ledger::{payable, record_payout, release};
+use crate::state::pool::{get_pool, get_vault_address};
+use crate::state::receipt::{check_position_owner, freeze_receipt};
+use crate::state::reward_calculation::{calculate_reward, stake_multiplier};
+use crate::state::stake::get_stake_data;
+use crate::state::staker::{create_staker_account, get_staker, remove_position, save_staker};
+use crate::state::stats::{create_stats_account, get_stats, record_unstake};
+use crate::state::unstake::return_nft;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+/// Returns staked NFTs to the wallets recorded in their positions, signed by the admin to wind a pool down.
+/// Receipt positions go to the holder of the receipt. Lock-ups and the unbonding period are ignored,
+/// the reward is paid out with `settle` and owed to the staker account of the owner otherwise
+pub fn force_unstake(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    count: u8,
+    settle: bool,
+) -> ProgramResult {
+    let batch = split_batch(
+        accounts,
+        FORCE_UNSTAKE_ACCOUNTS,
+        &FORCE_UNSTAKE_GROUP,
+        count as usize,
+    )?;
+
+    for accounts in batch.iter() {
+        force_unstake_position(
+            Accounts::new_with_owner(accounts)?,
+            program_id,
+            pool_id,
+            settle,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn force_unstake_position(
+    accounts: Accounts,
+    program_id: &Pubkey,
+    pool_id: u64,
+    settle: bool,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let reward_mint = pool.reward_mint;
+
+    let owner = accounts.owner();
+
+    let (stake_address, _stake_bump) = Pubkey::find_program_address(
+        &[
+            &accounts.pool_info.key.to_bytes(),
+            &accounts.nft_info.key.to_bytes(),
+        ],
+        program_id,
+    );
+
+    let vault_address = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
+
+    let owner_reward_holder =
+        spl_associated_token_account::get_associated_token_address(owner.key, &reward_mint);
+
+    let vault_reward_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.vault_info.key,
+        &reward_mint,
+    );
+
+    let owner_nft_holder = spl_associated_token_account::get_associated_token_address(
+        owner.key,
+        accounts.nft_info.key,
+    );
+
+    let vault_nft_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.vault_info.key,
+        accounts.nft_info.key,
+    );
+
+    // the owner's reward holder is only passed, and paid, when the reward is settled
+    if *accounts.token_info.key != spl_token::id()
+        || stake_address != *accounts.stake_info.key
+        || vault_address != *accounts.vault_info.key
+        || (settle && owner_reward_holder != *accounts.payer_reward_holder_info.key)
+        || vault_reward_holder != *accounts.vault_reward_holder_info.key
+        || owner_nft_holder != *accounts.payer_nft_holder_info.key
+        || vault_nft_holder != *accounts.vault_nft_holder_info.key
+        || reward_mint != *accounts.reward_mint_info.key
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let mut stake_data = get_stake_data(&accounts.stake_info.data.borrow())?;
+
+    // receipt positions go back to the current holder of the receipt
+    check_position_owner(&accounts, program_id, &stake_data)?;
+
+    // requested unstakes still hold their NFT until it is returned
+    if !stake_data.active && stake_data.unbonding_until == 0 {
+        return Err(ContractError::InactiveStaking.into());
+    }
+
+    let reward = if stake_data.active {
+        stake_data.accrued
+            + calculate_reward(
+                &pool.epochs,
+                clock.unix_timestamp as u64,
+                stake_data.timestamp,
+                stake_data.checkpoint,
+                stake_data.harvested + stake_data.accrued,
+                stake_multiplier(&stake_data),
+            )
+    } else {
+        0
+    };
+
+    create_stats_account(
+        accounts.payer,
+        accounts.stats_info,
+        accounts.system_program,
+        program_id,
+        &Rent::from_account_info(accounts.rent_info)?,
+        accounts.pool_info.key,
+    )?;
+
+    let mut stats = get_stats(program_id, accounts.pool_info.key, accounts.stats_info)?;
+
+    let mut staker = get_staker(
+        program_id,
+        accounts.pool_info.key,
+        owner.key,
+        accounts.staker_info,
+    )?;
+
+    // an underfunded vault pays what it can, the rest is owed to the staker account
+    let paid = if settle && !config.paused.claim {
+        payable(reward, vault_balance(&accounts.reward_accounts())?)
+    } else {
+        0
+    };
+
+    let owed = reward - paid;
+
+    if owed > 0 && staker.is_none() {
+        create_staker_account(
+            accounts.payer,
+            owner,
+            accounts.staker_info,
+            accounts.system_program,
+            program_id,
+            &Rent::from_account_info(accounts.rent_info)?,
+            accounts.pool_info.key,
+        )?;
+
+        staker = get_staker(
+            program_id,
+            accounts.pool_info.key,
+            owner.key,
+            accounts.staker_info,
+        )?;
+    }
+
+    if paid > 0 {
+        claim_transfer(&accounts.reward_accounts(), pool.vault_bump, paid)?;
+        record_payout(&mut pool.ledger, &mut stake_data, paid);
+        stats.rewards_distributed += paid;
+        stake_data.withdrawn += paid;
+    }
+
+    if let Some(staker) = staker.as_mut() {
+        staker.rewards_claimed += paid;
+        staker.owed += owed;
+    }
+
+    stake_data.harvested += reward;
+    stake_data.accrued = 0;
+
+    // only the holder can burn the receipt, it is frozen in their wallet instead
+    // and the next stake of the NFT mints a new one
+    if stake_data.receipt {
+        freeze_receipt(&accounts, pool.vault_bump)?;
+        stake_data.receipt = false;
+        stake_data.staker = *owner.key;
+    }
+
+    return_nft(&accounts, &stake_data, pool.vault_bump)?;
+
+    if stake_data.active {
+        release(&mut pool.ledger, &mut stake_data, owed);
+        record_unstake(&mut stats, &stake_data.whitelist);
+
+        stake_data.active = false;
+        stake_data.checkpoint = clock.unix_timestamp as u64;
+    }
+
+    stake_data.unbonding_until = 0;
+    stake_data.serialize(&mut &mut accounts.stake_info.data.borrow_mut()[..])?;
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+    stats.serialize(&mut &mut accounts.stats_info.data.borrow_mut()[..])?;
+
+    if let Some(mut staker) = staker {
+        remove_position(&mut staker, &stake_data.mint);
+        save_staker(&staker, accounts.staker_info, owner)?;
+    }
+
+    Ok(())
+}
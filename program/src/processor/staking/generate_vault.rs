@@ -1,5 +1,7 @@
-use crate::consts::{ADMIN, VAULT};
+use crate::consts::VAULT;
 use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pool::{get_pool, get_vault_address};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program::{invoke, invoke_signed};
@@ -9,22 +11,26 @@ use solana_program::rent::Rent;
 use solana_program::system_instruction;
 use solana_program::sysvar::Sysvar;
 
-pub fn generate_vault(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+pub fn generate_vault(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+) -> ProgramResult {
     let accounts = Accounts::new(accounts)?;
 
     let rent = &Rent::from_account_info(accounts.rent_info)?;
 
-    let (vault_pda, vault_bump_seed) = Pubkey::find_program_address(&[VAULT], program_id);
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    let pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let vault_pda = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
 
     if accounts.pda.key != &vault_pda {
         return Err(ContractError::InvalidInstructionData.into());
     }
 
-    let admin = ADMIN.parse::<Pubkey>().unwrap();
-
-    if *accounts.payer.key != admin || !accounts.payer.is_signer {
-        return Err(ContractError::UnauthorisedAccess.into());
-    }
+    check_admin(&config, accounts.payer)?;
 
     if accounts.pda.owner != program_id {
         let required_lamports = rent
@@ -44,7 +50,11 @@ pub fn generate_vault(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramR
         invoke_signed(
             &system_instruction::assign(&vault_pda, program_id),
             &[accounts.pda.clone(), accounts.system_program.clone()],
-            &[&[VAULT, &[vault_bump_seed]]],
+            &[&[
+                VAULT,
+                &accounts.pool_info.key.to_bytes(),
+                &[pool.vault_bump],
+            ]],
         )?;
     }
 
@@ -57,6 +67,8 @@ pub struct Accounts<'a, 'b> {
     pub system_program: &'a AccountInfo<'b>,
     pub pda: &'a AccountInfo<'b>,
     pub rent_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
 }
 
 impl<'a, 'b> Accounts<'a, 'b> {
@@ -69,6 +81,8 @@ impl<'a, 'b> Accounts<'a, 'b> {
             system_program: next_account_info(acc_iter)?,
             pda: next_account_info(acc_iter)?,
             rent_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
         })
     }
 }
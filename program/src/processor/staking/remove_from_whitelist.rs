@@ -0,0 +1,67 @@
+use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pda::close_pda_account;
+use crate::state::pool::get_pool;
+use crate::state::whitelist::get_whitelist_address;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Refuses new stakes of the creator and returns the rent to the admin.
+/// NFTs that are already staked keep accruing and can still be claimed and unstaked.
+pub fn remove_from_whitelist(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let (data_address, _) = get_whitelist_address(
+        program_id,
+        accounts.pool_info.key,
+        accounts.creator_info.key,
+    );
+
+    if *accounts.whitelist_info.key != data_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if accounts.whitelist_info.owner != program_id {
+        return Err(ContractError::WhitelistError.into());
+    }
+
+    close_pda_account(accounts.whitelist_info, accounts.payer);
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub creator_info: &'a AccountInfo<'b>,
+    pub whitelist_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            creator_info: next_account_info(acc_iter)?,
+            whitelist_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pool::get_pool;
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Publishes the Merkle root of `(mint, boost)` pairs of a pool, `None` turns boosts off.
+/// Open positions keep the boost they were staked with.
+pub fn set_boost_root(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    root: Option<[u8; 32]>,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    pool.boost_root = root;
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+        })
+    }
+}
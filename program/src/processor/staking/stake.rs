@@ -1,7 +1,17 @@
-use crate::consts::{VAULT, WHITELIST};
+use crate::consts::BASIS_POINTS;
 use crate::error::ContractError;
+use crate::state::ledger::{funded, reserve};
+use crate::state::merkle::{boost_leaf, verify_proof};
+use crate::state::metadata::get_whitelist_key;
+use crate::state::pool::{current_schedule, get_pool, get_vault_address, lock_terms};
+use crate::state::receipt::mint_receipt;
+use crate::state::reward_calculation::{apply_multiplier, stake_multiplier};
+use crate::state::soft_stake::freeze_nft;
 use crate::state::stake::{check_metadata_account, pay_rent, transfer_nft_to_assoc};
-use crate::state::structs::StakeData;
+use crate::state::staker::{add_position, create_staker_account, get_staker};
+use crate::state::stats::{create_stats_account, get_stats, record_stake};
+use crate::state::structs::{Boost, Eligibility, StakeData};
+use crate::state::whitelist::{get_enabled_whitelist, get_whitelist_address};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::clock::Clock;
@@ -11,19 +21,33 @@ use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
-pub fn stake(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+pub fn stake(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    eligibility: Eligibility,
+    boost: Option<Boost>,
+    lock_tier: Option<u8>,
+) -> ProgramResult {
     let accounts = Accounts::new(accounts)?;
 
     let clock = Clock::get()?;
 
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
     if *accounts.token_program.key != spl_token::id() {
         return Err(ContractError::InvalidInstructionData.into());
     }
 
     let rent = &Rent::from_account_info(accounts.rent_info)?;
 
-    let (stake_data, stake_data_bump) =
-        Pubkey::find_program_address(&[&accounts.mint.key.to_bytes()], program_id);
+    let (stake_data, stake_data_bump) = Pubkey::find_program_address(
+        &[
+            &accounts.pool_info.key.to_bytes(),
+            &accounts.mint.key.to_bytes(),
+        ],
+        program_id,
+    );
 
     if !accounts.payer.is_signer {
         return Err(ContractError::UnauthorisedAccess.into());
@@ -33,49 +57,135 @@ pub fn stake(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         return Err(ContractError::InvalidInstructionData.into());
     }
 
-    pay_rent(&accounts, program_id, rent, stake_data, stake_data_bump)?;
+    check_metadata_account(accounts.mint, accounts.metadata_account_info)?;
+
+    let whitelist_key =
+        get_whitelist_key(&accounts.metadata_account_info.data.borrow(), eligibility)?;
+
+    let (wl_data_address, _wl_data_address_bump) =
+        get_whitelist_address(program_id, accounts.pool_info.key, &whitelist_key);
+
+    if *accounts.whitelist_info.key != wl_data_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let whitelist = get_enabled_whitelist(program_id, accounts.whitelist_info)?;
+
+    if whitelist.eligibility != eligibility {
+        return Err(ContractError::WhitelistError.into());
+    }
+
+    let boost = match boost {
+        Some(boost) => match pool.boost_root {
+            Some(root)
+                if verify_proof(
+                    &root,
+                    boost_leaf(accounts.mint.key, boost.boost),
+                    &boost.proof,
+                ) =>
+            {
+                boost.boost
+            }
+            _ => return Err(ContractError::InvalidProof.into()),
+        },
+        None => BASIS_POINTS,
+    };
+
+    let (lock_until, lock_multiplier) = lock_terms(&pool, lock_tier, clock.unix_timestamp as u64)?;
+
+    pay_rent(&accounts, program_id, rent, stake_data_bump)?;
 
-    let harvested =
+    // a reward left unpaid by the previous unstake is owed to its staker account,
+    // the position itself starts from scratch
+    let (harvested, reserved, stakes) =
         if let Ok(data) = StakeData::try_from_slice(&accounts.stake_data_info.data.borrow()) {
-            data.harvested
+            (data.harvested, data.reserved, data.stakes)
         } else {
-            0
+            (0, 0, 0)
         };
 
-    let stake_struct = StakeData {
+    let mut stake_struct = StakeData {
         timestamp: clock.unix_timestamp as u64,
         staker: *accounts.payer.key,
         harvested,
         active: true,
         withdrawn: 0,
         mint: *accounts.mint.key,
+        accrued: 0,
+        checkpoint: clock.unix_timestamp as u64,
+        whitelist: wl_data_address,
+        reward_multiplier: whitelist.reward_multiplier,
+        boost,
+        reserved,
+        frozen: pool.options.soft_staking,
+        lock_until,
+        lock_multiplier,
+        unbonding_until: 0,
+        receipt: pool.options.mint_receipts,
+        stakes: stakes + 1,
+        raised_at: 0,
     };
-    stake_struct.serialize(&mut &mut accounts.stake_data_info.data.borrow_mut()[..])?;
 
-    check_metadata_account(accounts.mint, accounts.metadata_account_info)?;
+    let max_payout = apply_multiplier(
+        current_schedule(&pool).max_payout_per_nft,
+        stake_multiplier(&stake_struct),
+    );
+    reserve(
+        &mut pool.ledger,
+        &mut stake_struct,
+        max_payout.saturating_sub(harvested),
+    );
 
-    let metadata =
-        spl_token_metadata::state::Metadata::from_account_info(accounts.metadata_account_info)?;
-    let creators = metadata.data.creators.unwrap();
-    let creator = creators.first().unwrap();
-    let creator_address = creator.address;
+    if pool.options.require_funded && pool.ledger.liability > funded(&pool.ledger) {
+        return Err(ContractError::InsufficientRewards.into());
+    }
 
-    let (wl_data_address, _wl_data_address_bump) =
-        Pubkey::find_program_address(&[WHITELIST, &creator_address.to_bytes()], program_id);
+    create_stats_account(
+        accounts.payer,
+        accounts.stats_info,
+        accounts.sys_info,
+        program_id,
+        rent,
+        accounts.pool_info.key,
+    )?;
 
-    if *accounts.whitelist_info.key != wl_data_address {
-        return Err(ContractError::InvalidInstructionData.into());
-    }
+    let mut stats = get_stats(program_id, accounts.pool_info.key, accounts.stats_info)?;
 
-    if accounts.whitelist_info.owner != program_id {
-        return Err(ContractError::WhitelistError.into());
+    if stats.total_active >= pool.options.max_staked {
+        return Err(ContractError::PoolFull.into());
     }
 
-    if !creator.verified {
-        return Err(ContractError::UnverifiedAddress.into());
+    record_stake(&mut stats, &wl_data_address)?;
+
+    stake_struct.serialize(&mut &mut accounts.stake_data_info.data.borrow_mut()[..])?;
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+    stats.serialize(&mut &mut accounts.stats_info.data.borrow_mut()[..])?;
+
+    // receipt positions change hands with their token, the staker account doesn't list them
+    if !stake_struct.receipt {
+        create_staker_account(
+            accounts.payer,
+            accounts.payer,
+            accounts.staker_info,
+            accounts.sys_info,
+            program_id,
+            rent,
+            accounts.pool_info.key,
+        )?;
+
+        let mut staker = get_staker(
+            program_id,
+            accounts.pool_info.key,
+            accounts.payer.key,
+            accounts.staker_info,
+        )?
+        .ok_or(ContractError::NotInitialized)?;
+        add_position(&mut staker, accounts.mint.key)?;
+
+        staker.serialize(&mut &mut accounts.staker_info.data.borrow_mut()[..])?;
     }
 
-    let (vault, _vault_bump) = Pubkey::find_program_address(&[VAULT], program_id);
+    let vault = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
 
     if vault != *accounts.vault_info.key {
         return Err(ContractError::InvalidInstructionData.into());
@@ -95,7 +205,21 @@ pub fn stake(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         return Err(ContractError::InvalidInstructionData.into());
     }
 
-    transfer_nft_to_assoc(&accounts)?;
+    if stake_struct.frozen {
+        freeze_nft(&accounts, pool.vault_bump)?;
+    } else {
+        transfer_nft_to_assoc(&accounts)?;
+    }
+
+    if stake_struct.receipt {
+        mint_receipt(
+            &accounts,
+            program_id,
+            rent,
+            pool.vault_bump,
+            stake_struct.stakes,
+        )?;
+    }
 
     Ok(())
 }
@@ -114,6 +238,14 @@ pub struct Accounts<'a, 'b> {
     pub token_assoc: &'a AccountInfo<'b>,
     pub stake_data_info: &'a AccountInfo<'b>,
     pub whitelist_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub stats_info: &'a AccountInfo<'b>,
+    pub staker_info: &'a AccountInfo<'b>,
+    pub edition_info: &'a AccountInfo<'b>,
+    pub metadata_program_info: &'a AccountInfo<'b>,
+    pub receipt_mint_info: &'a AccountInfo<'b>,
+    pub receipt_holder_info: &'a AccountInfo<'b>,
 }
 
 impl<'a, 'b> Accounts<'a, 'b> {
@@ -134,6 +266,14 @@ impl<'a, 'b> Accounts<'a, 'b> {
             token_assoc: next_account_info(acc_iter)?,
             stake_data_info: next_account_info(acc_iter)?,
             whitelist_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            stats_info: next_account_info(acc_iter)?,
+            staker_info: next_account_info(acc_iter)?,
+            edition_info: next_account_info(acc_iter)?,
+            metadata_program_info: next_account_info(acc_iter)?,
+            receipt_mint_info: next_account_info(acc_iter)?,
+            receipt_holder_info: next_account_info(acc_iter)?,
         })
     }
 }
@@ -0,0 +1,28 @@
+use crate::processor::staking::stake::stake;
+use crate::state::batch::{split_batch, STAKE_ACCOUNTS, STAKE_GROUP};
+use crate::state::structs::StakeEntry;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::pubkey::Pubkey;
+
+pub fn stake_batch(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    entries: Vec<StakeEntry>,
+) -> ProgramResult {
+    let batch = split_batch(accounts, STAKE_ACCOUNTS, &STAKE_GROUP, entries.len())?;
+
+    for (accounts, entry) in batch.iter().zip(entries) {
+        stake(
+            accounts,
+            program_id,
+            pool_id,
+            entry.eligibility,
+            entry.boost,
+            entry.lock_tier,
+        )?;
+    }
+
+    Ok(())
+}
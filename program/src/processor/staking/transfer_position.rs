@@ -0,0 +1,200 @@
+use crate::error::ContractError;
+use crate::processor::staking::claim::Accounts as ClaimAccounts;
+use crate::state::batch::CLAIM_ACCOUNTS;
+use crate::state::claim::claim_transfer;
+use crate::state::config::get_platform_config;
+use crate::state::ledger::record_payout;
+use crate::state::pool::{get_pool, get_vault_address};
+use crate::state::reward_calculation::{calculate_reward, stake_multiplier};
+use crate::state::stake::get_stake_data;
+use crate::state::staker::{
+    add_position, create_staker_account, get_staker, remove_position, save_staker,
+};
+use crate::state::stats::{create_stats_account, get_stats};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+/// Hands a position over to `new_staker` without unstaking, the stake timestamp is kept
+pub fn transfer_position(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+    let position = &accounts.position;
+
+    let clock = Clock::get()?;
+
+    let mut pool = get_pool(program_id, position.pool_info, pool_id)?;
+
+    let reward_mint = pool.reward_mint;
+
+    let (stake_address, _stake_bump) = Pubkey::find_program_address(
+        &[
+            &position.pool_info.key.to_bytes(),
+            &position.nft_info.key.to_bytes(),
+        ],
+        program_id,
+    );
+
+    let vault_address = get_vault_address(program_id, position.pool_info.key, &pool)?;
+
+    let payer_reward_holder = spl_associated_token_account::get_associated_token_address(
+        position.payer.key,
+        &reward_mint,
+    );
+
+    let vault_reward_holder = spl_associated_token_account::get_associated_token_address(
+        position.vault_info.key,
+        &reward_mint,
+    );
+
+    if !position.payer.is_signer {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    if *position.token_info.key != spl_token::id()
+        || stake_address != *position.stake_info.key
+        || vault_address != *position.vault_info.key
+        || payer_reward_holder != *position.payer_reward_holder_info.key
+        || vault_reward_holder != *position.vault_reward_holder_info.key
+        || reward_mint != *position.reward_mint_info.key
+        || position.payer.key == accounts.new_staker.key
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let mut stake_data = get_stake_data(&position.stake_info.data.borrow())?;
+
+    if !stake_data.active {
+        return Err(ContractError::InactiveStaking.into());
+    }
+
+    if stake_data.staker != *position.payer.key {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    // the receipt token is transferred instead
+    if stake_data.receipt {
+        return Err(ContractError::ReceiptPosition.into());
+    }
+
+    // a soft staked NFT is frozen in the current staker's wallet and can't follow the position
+    if stake_data.frozen {
+        return Err(ContractError::SoftStaked.into());
+    }
+
+    create_stats_account(
+        position.payer,
+        position.stats_info,
+        position.system_program,
+        program_id,
+        &Rent::from_account_info(position.rent_info)?,
+        position.pool_info.key,
+    )?;
+
+    let mut stats = get_stats(program_id, position.pool_info.key, position.stats_info)?;
+
+    let mut staker = get_staker(
+        program_id,
+        position.pool_info.key,
+        position.payer.key,
+        position.staker_info,
+    )?;
+
+    // otherwise, or while the position is locked, the reward earned so far travels with it
+    if pool.options.settle_on_transfer && clock.unix_timestamp as u64 >= stake_data.lock_until {
+        let config = get_platform_config(program_id, position.config_info)?;
+
+        if config.paused.claim {
+            return Err(ContractError::Paused.into());
+        }
+
+        let reward = stake_data.accrued
+            + calculate_reward(
+                &pool.epochs,
+                clock.unix_timestamp as u64,
+                stake_data.timestamp,
+                stake_data.checkpoint,
+                stake_data.harvested + stake_data.accrued,
+                stake_multiplier(&stake_data),
+            );
+
+        claim_transfer(&position.reward_accounts(), pool.vault_bump, reward)?;
+
+        record_payout(&mut pool.ledger, &mut stake_data, reward);
+        stats.rewards_distributed += reward;
+        if let Some(staker) = staker.as_mut() {
+            staker.rewards_claimed += reward;
+        }
+
+        stake_data.harvested += reward;
+        stake_data.withdrawn += reward;
+        stake_data.accrued = 0;
+        stake_data.checkpoint = clock.unix_timestamp as u64;
+    }
+
+    create_staker_account(
+        position.payer,
+        accounts.new_staker,
+        accounts.new_staker_info,
+        position.system_program,
+        program_id,
+        &Rent::from_account_info(position.rent_info)?,
+        position.pool_info.key,
+    )?;
+
+    let mut new_staker = get_staker(
+        program_id,
+        position.pool_info.key,
+        accounts.new_staker.key,
+        accounts.new_staker_info,
+    )?
+    .ok_or(ContractError::NotInitialized)?;
+    add_position(&mut new_staker, &stake_data.mint)?;
+
+    stake_data.staker = *accounts.new_staker.key;
+    stake_data.serialize(&mut &mut position.stake_info.data.borrow_mut()[..])?;
+    pool.serialize(&mut &mut position.pool_info.data.borrow_mut()[..])?;
+    stats.serialize(&mut &mut position.stats_info.data.borrow_mut()[..])?;
+    new_staker.serialize(&mut &mut accounts.new_staker_info.data.borrow_mut()[..])?;
+
+    if let Some(mut staker) = staker {
+        remove_position(&mut staker, &stake_data.mint);
+        save_staker(&staker, position.staker_info, position.payer)?;
+    }
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    /// Same accounts as `Claim`, signed by the current staker
+    pub position: ClaimAccounts<'a, 'b>,
+    pub new_staker: &'a AccountInfo<'b>,
+    pub new_staker_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        if accounts.len() < CLAIM_ACCOUNTS {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (position, accounts) = accounts.split_at(CLAIM_ACCOUNTS);
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            position: ClaimAccounts::new(position)?,
+            new_staker: next_account_info(acc_iter)?,
+            new_staker_info: next_account_info(acc_iter)?,
+        })
+    }
+}
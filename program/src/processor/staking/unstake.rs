@@ -1,28 +1,67 @@
-use crate::consts::{REWARD_MINT, VAULT, WHITELIST};
 use crate::error::ContractError;
 use crate::processor::staking::claim::Accounts;
-use crate::state::claim::claim_transfer;
-use crate::state::reward_calculation::calculate_reward;
+use crate::state::claim::{claim_transfer, vault_balance};
+use crate::state::config::get_platform_config;
+use crate::state::ledger::{payable, record_payout, release};
+use crate::state::pool::{get_pool, get_vault_address, unlocked_reward};
+use crate::state::receipt::{burn_receipt, check_position_owner};
+use crate::state::reward_calculation::{calculate_reward, stake_multiplier};
+use crate::state::staker::{create_staker_account, get_staker, remove_position, save_staker};
+use crate::state::stats::{create_stats_account, get_stats, record_unstake};
 use crate::state::structs::StakeData;
+use crate::state::unstake::return_nft;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::account_info::AccountInfo;
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
-use solana_program::program::{invoke, invoke_signed};
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
-pub fn unstake(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
-    let accounts = Accounts::new(accounts)?;
+pub fn unstake(accounts: &[AccountInfo], program_id: &Pubkey, pool_id: u64) -> ProgramResult {
+    unstake_position(Accounts::new(accounts)?, program_id, pool_id, false)
+}
+
+/// Same as `unstake`, the reward is paid to the recipient token account instead of the payer's ATA
+pub fn unstake_to(accounts: &[AccountInfo], program_id: &Pubkey, pool_id: u64) -> ProgramResult {
+    unstake_position(
+        Accounts::new_with_recipient(accounts)?,
+        program_id,
+        pool_id,
+        false,
+    )
+}
 
+/// Starts the unbonding period, the position stops earning and is paid out but the NFT stays staked
+pub fn request_unstake(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+) -> ProgramResult {
+    unstake_position(Accounts::new(accounts)?, program_id, pool_id, true)
+}
+
+fn unstake_position(
+    accounts: Accounts,
+    program_id: &Pubkey,
+    pool_id: u64,
+    request: bool,
+) -> ProgramResult {
     let clock = Clock::get()?;
 
-    let reward_mint = REWARD_MINT.parse::<Pubkey>().unwrap();
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
 
-    let (stake_address, _stake_bump) =
-        Pubkey::find_program_address(&[&accounts.nft_info.key.to_bytes()], program_id);
+    let reward_mint = pool.reward_mint;
 
-    let (vault_address, vault_bump) = Pubkey::find_program_address(&[VAULT], program_id);
+    let (stake_address, _stake_bump) = Pubkey::find_program_address(
+        &[
+            &accounts.pool_info.key.to_bytes(),
+            &accounts.nft_info.key.to_bytes(),
+        ],
+        program_id,
+    );
+
+    let vault_address = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
 
     let payer_reward_holder = spl_associated_token_account::get_associated_token_address(
         accounts.payer.key,
@@ -70,18 +109,6 @@ pub fn unstake(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         return Err(ContractError::InvalidInstructionData.into());
     }
 
-    let metadata = spl_token_metadata::state::Metadata::from_account_info(accounts.metadata_info)?;
-    let creators = metadata.data.creators.unwrap();
-    let creator = creators.first().unwrap();
-    let creator_address = creator.address;
-
-    let (wl_data_address, _wl_data_address_bump) =
-        Pubkey::find_program_address(&[WHITELIST, &creator_address.to_bytes()], program_id);
-
-    if *accounts.whitelist_info.key != wl_data_address {
-        return Err(ContractError::InvalidInstructionData.into());
-    }
-
     let mut stake_data =
         if let Ok(data) = StakeData::try_from_slice(&accounts.stake_info.data.borrow()) {
             data
@@ -89,85 +116,132 @@ pub fn unstake(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
             return Err(ContractError::DeserializeError.into());
         };
 
-    if !creator.verified {
-        return Err(ContractError::UnverifiedAddress.into());
+    // entry the NFT was staked under, NFTs of a removed entry can still be unstaked
+    if *accounts.whitelist_info.key != stake_data.whitelist {
+        return Err(ContractError::InvalidInstructionData.into());
     }
 
     if !stake_data.active {
         return Err(ContractError::InactiveStaking.into());
     }
 
-    if stake_data.staker != *accounts.payer.key {
-        return Err(ContractError::UnauthorisedAccess.into());
+    check_position_owner(&accounts, program_id, &stake_data)?;
+
+    if !request && pool.options.unbonding_period > 0 {
+        return Err(ContractError::UnbondingRequired.into());
     }
 
-    let reward = calculate_reward(
+    let earned = calculate_reward(
+        &pool.epochs,
         clock.unix_timestamp as u64,
         stake_data.timestamp,
-        stake_data.harvested,
-        stake_data.withdrawn,
+        stake_data.checkpoint,
+        stake_data.harvested + stake_data.accrued,
+        stake_multiplier(&stake_data),
     );
 
-    claim_transfer(&accounts, vault_bump, reward)?;
-
-    if accounts.payer_nft_holder_info.owner != accounts.token_info.key {
-        invoke(
-            &spl_associated_token_account::create_associated_token_account(
-                accounts.payer.key,
-                accounts.payer.key,
-                accounts.nft_info.key,
-            ),
-            &[
-                accounts.payer.clone(),
-                accounts.payer_nft_holder_info.clone(),
-                accounts.payer.clone(),
-                accounts.nft_info.clone(),
-                accounts.system_program.clone(),
-                accounts.token_info.clone(),
-                accounts.rent_info.clone(),
-                accounts.assoc_acccount_info.clone(),
-            ],
-        )?;
-    }
+    let reward = unlocked_reward(
+        &pool.options,
+        &stake_data,
+        earned,
+        clock.unix_timestamp as u64,
+    )?;
 
-    invoke_signed(
-        &spl_token::instruction::transfer(
-            accounts.token_info.key,
-            accounts.vault_nft_holder_info.key,
-            accounts.payer_nft_holder_info.key,
-            accounts.vault_info.key,
-            &[],
-            1,
-        )?,
-        &[
-            accounts.vault_nft_holder_info.clone(),
-            accounts.payer_nft_holder_info.clone(),
-            accounts.vault_info.clone(),
-            accounts.token_info.clone(),
-        ],
-        &[&[VAULT, &[vault_bump]]],
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    create_stats_account(
+        accounts.payer,
+        accounts.stats_info,
+        accounts.system_program,
+        program_id,
+        &Rent::from_account_info(accounts.rent_info)?,
+        accounts.pool_info.key,
     )?;
 
-    invoke_signed(
-        &spl_token::instruction::close_account(
-            accounts.token_info.key,
-            accounts.vault_nft_holder_info.key,
-            accounts.payer.key,
-            accounts.vault_info.key,
-            &[],
-        )?,
-        &[
-            accounts.vault_nft_holder_info.clone(),
-            accounts.payer.clone(),
-            accounts.vault_info.clone(),
-            accounts.token_info.clone(),
-        ],
-        &[&[VAULT, &[vault_bump]]],
+    let mut stats = get_stats(program_id, accounts.pool_info.key, accounts.stats_info)?;
+
+    let mut staker = get_staker(
+        program_id,
+        accounts.pool_info.key,
+        accounts.payer.key,
+        accounts.staker_info,
     )?;
-    stake_data.active = false;
+
+    // with claims frozen or the vault short the NFT goes back anyway,
+    // the unpaid reward is owed to the staker account and claimable later
+    let paid = if config.paused.claim {
+        0
+    } else {
+        payable(reward, vault_balance(&accounts.reward_accounts())?)
+    };
+
+    let owed = reward - paid;
+
+    if owed > 0 && staker.is_none() {
+        create_staker_account(
+            accounts.payer,
+            accounts.payer,
+            accounts.staker_info,
+            accounts.system_program,
+            program_id,
+            &Rent::from_account_info(accounts.rent_info)?,
+            accounts.pool_info.key,
+        )?;
+
+        staker = get_staker(
+            program_id,
+            accounts.pool_info.key,
+            accounts.payer.key,
+            accounts.staker_info,
+        )?;
+    }
+
+    if paid > 0 {
+        claim_transfer(&accounts.reward_accounts(), pool.vault_bump, paid)?;
+        record_payout(&mut pool.ledger, &mut stake_data, paid);
+        stats.rewards_distributed += paid;
+        stake_data.withdrawn += paid;
+    }
+
+    if let Some(staker) = staker.as_mut() {
+        staker.rewards_claimed += paid;
+        staker.owed += owed;
+    }
+
+    // the owed part counts against the payout cap of the NFT like the paid one
     stake_data.harvested += reward;
-    stake_data.withdrawn += reward;
+    stake_data.accrued = 0;
+
+    // the position leaves with its holder, who becomes the staker
+    if stake_data.receipt {
+        burn_receipt(&accounts)?;
+        stake_data.receipt = false;
+        stake_data.staker = *accounts.payer.key;
+    }
+
+    // a requested unstake keeps the NFT until `CompleteUnstake`
+    if request {
+        stake_data.unbonding_until = clock.unix_timestamp as u64 + pool.options.unbonding_period;
+    } else {
+        return_nft(&accounts, &stake_data, pool.vault_bump)?;
+    }
+
+    release(&mut pool.ledger, &mut stake_data, owed);
+    record_unstake(&mut stats, &stake_data.whitelist);
+
+    stake_data.active = false;
+    stake_data.checkpoint = clock.unix_timestamp as u64;
     stake_data.serialize(&mut &mut accounts.stake_info.data.borrow_mut()[..])?;
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+    stats.serialize(&mut &mut accounts.stats_info.data.borrow_mut()[..])?;
+
+    if let Some(mut staker) = staker {
+        if !request {
+            remove_position(&mut staker, &stake_data.mint);
+        }
+
+        save_staker(&staker, accounts.staker_info, accounts.payer)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,67 @@
+use crate::consts::MAX_SCHEDULE_EPOCHS;
+use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pool::{check_schedule, get_pool, raise_cap};
+use crate::state::structs::{RewardSchedule, ScheduleEpoch};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+pub fn update_reward_schedule(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    schedule: RewardSchedule,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    check_schedule(&schedule)?;
+
+    raise_cap(&mut pool, &schedule)?;
+
+    let start = Clock::get()?.unix_timestamp as u64;
+
+    // a second update within the same second replaces the epoch it opened
+    if pool.epochs.last().unwrap().start == start {
+        pool.epochs.pop();
+    }
+
+    if pool.epochs.len() >= MAX_SCHEDULE_EPOCHS {
+        return Err(ContractError::ScheduleHistoryFull.into());
+    }
+
+    pool.epochs.push(ScheduleEpoch { start, schedule });
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+        })
+    }
+}
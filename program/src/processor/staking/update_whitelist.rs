@@ -0,0 +1,83 @@
+use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::pool::get_pool;
+use crate::state::structs::WhitelistData;
+use crate::state::whitelist::get_whitelist_address;
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Changes the multiplier of new stakes and enables or disables the entry.
+/// Open positions keep the multiplier they were staked with.
+pub fn update_whitelist(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    reward_multiplier: u64,
+    enabled: bool,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    if reward_multiplier == 0 {
+        return Err(ContractError::InvalidMultiplier.into());
+    }
+
+    let (data_address, _) = get_whitelist_address(
+        program_id,
+        accounts.pool_info.key,
+        accounts.creator_info.key,
+    );
+
+    if *accounts.whitelist_info.key != data_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if accounts.whitelist_info.owner != program_id {
+        return Err(ContractError::WhitelistError.into());
+    }
+
+    let mut whitelist =
+        match try_from_slice_unchecked::<WhitelistData>(&accounts.whitelist_info.data.borrow()) {
+            Ok(data) => data,
+            Err(_) => return Err(ContractError::DeserializeError.into()),
+        };
+
+    whitelist.reward_multiplier = reward_multiplier;
+    whitelist.enabled = enabled;
+    whitelist.serialize(&mut &mut accounts.whitelist_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub creator_info: &'a AccountInfo<'b>,
+    pub whitelist_info: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            creator_info: next_account_info(acc_iter)?,
+            whitelist_info: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+        })
+    }
+}
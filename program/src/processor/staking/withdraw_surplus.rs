@@ -0,0 +1,149 @@
+use crate::consts::VAULT;
+use crate::error::ContractError;
+use crate::state::config::{check_admin, get_platform_config};
+use crate::state::ledger::surplus;
+use crate::state::pool::{get_pool, get_vault_address};
+use borsh::BorshSerialize;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::Account;
+
+/// Moves reward tokens above the outstanding liability from the vault to the admin.
+/// Only the reward token account of the vault can be drained, never a staked NFT.
+pub fn withdraw_surplus(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    pool_id: u64,
+    amount: u64,
+) -> ProgramResult {
+    let accounts = Accounts::new(accounts)?;
+
+    let config = get_platform_config(program_id, accounts.config_info)?;
+
+    check_admin(&config, accounts.payer)?;
+
+    let mut pool = get_pool(program_id, accounts.pool_info, pool_id)?;
+
+    let vault_address = get_vault_address(program_id, accounts.pool_info.key, &pool)?;
+
+    let vault_reward_holder = spl_associated_token_account::get_associated_token_address(
+        &vault_address,
+        &pool.reward_mint,
+    );
+
+    let payer_reward_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.payer.key,
+        &pool.reward_mint,
+    );
+
+    if *accounts.token_info.key != spl_token::id()
+        || vault_address != *accounts.vault_info.key
+        || vault_reward_holder != *accounts.vault_reward_holder_info.key
+        || payer_reward_holder != *accounts.payer_reward_holder_info.key
+        || pool.reward_mint != *accounts.reward_mint_info.key
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if accounts.vault_reward_holder_info.owner != accounts.token_info.key {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let vault_rewards = Account::unpack(&accounts.vault_reward_holder_info.data.borrow())?;
+
+    // NFT custody accounts of the vault hold other mints
+    if vault_rewards.mint != pool.reward_mint || vault_rewards.owner != vault_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if amount > surplus(&pool.ledger, vault_rewards.amount) {
+        return Err(ContractError::SurplusExceeded.into());
+    }
+
+    if accounts.payer_reward_holder_info.owner != accounts.token_info.key {
+        invoke(
+            &spl_associated_token_account::create_associated_token_account(
+                accounts.payer.key,
+                accounts.payer.key,
+                accounts.reward_mint_info.key,
+            ),
+            &[
+                accounts.payer.clone(),
+                accounts.payer_reward_holder_info.clone(),
+                accounts.payer.clone(),
+                accounts.reward_mint_info.clone(),
+                accounts.sys_info.clone(),
+                accounts.token_info.clone(),
+                accounts.rent_info.clone(),
+                accounts.assoc_acccount_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            accounts.token_info.key,
+            accounts.vault_reward_holder_info.key,
+            accounts.payer_reward_holder_info.key,
+            accounts.vault_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            accounts.vault_reward_holder_info.clone(),
+            accounts.payer_reward_holder_info.clone(),
+            accounts.vault_info.clone(),
+            accounts.token_info.clone(),
+        ],
+        &[&[
+            VAULT,
+            &accounts.pool_info.key.to_bytes(),
+            &[pool.vault_bump],
+        ]],
+    )?;
+
+    pool.ledger.total_withdrawn += amount;
+    pool.serialize(&mut &mut accounts.pool_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub struct Accounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    pub config_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+    pub vault_info: &'a AccountInfo<'b>,
+    pub vault_reward_holder_info: &'a AccountInfo<'b>,
+    pub payer_reward_holder_info: &'a AccountInfo<'b>,
+    pub reward_mint_info: &'a AccountInfo<'b>,
+    pub token_info: &'a AccountInfo<'b>,
+    pub sys_info: &'a AccountInfo<'b>,
+    pub rent_info: &'a AccountInfo<'b>,
+    pub assoc_acccount_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> Accounts<'a, 'b> {
+    #[allow(dead_code)]
+    pub fn new(accounts: &'a [AccountInfo<'b>]) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let acc_iter = &mut accounts.iter();
+
+        Ok(Accounts {
+            payer: next_account_info(acc_iter)?,
+            config_info: next_account_info(acc_iter)?,
+            pool_info: next_account_info(acc_iter)?,
+            vault_info: next_account_info(acc_iter)?,
+            vault_reward_holder_info: next_account_info(acc_iter)?,
+            payer_reward_holder_info: next_account_info(acc_iter)?,
+            reward_mint_info: next_account_info(acc_iter)?,
+            token_info: next_account_info(acc_iter)?,
+            sys_info: next_account_info(acc_iter)?,
+            rent_info: next_account_info(acc_iter)?,
+            assoc_acccount_info: next_account_info(acc_iter)?,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::error::ContractError;
+use solana_program::instruction::AccountMeta;
+use solana_program::program_error::ProgramError;
+use solana_program::system_program;
+
+/// Number of accounts of `Stake`
+pub const STAKE_ACCOUNTS: usize = 20;
+
+/// Positions of the per-NFT accounts in the `Stake` account list:
+/// nft, metadata, source, destination, stake data, whitelist, edition, receipt mint and holder
+pub const STAKE_GROUP: [usize; 9] = [1, 2, 4, 5, 10, 11, 16, 18, 19];
+
+/// Number of accounts of `Claim` and `Unstake`
+pub const CLAIM_ACCOUNTS: usize = 23;
+
+/// Positions of the per-NFT accounts in the `Claim` and `Unstake` account list:
+/// nft, stake data, payer and vault NFT holders, metadata, whitelist, edition, receipt mint and holder
+pub const CLAIM_GROUP: [usize; 9] = [2, 6, 10, 11, 12, 13, 19, 21, 22];
+
+/// Number of accounts of `ForceUnstake`, the `Unstake` list followed by the owner
+pub const FORCE_UNSTAKE_ACCOUNTS: usize = 24;
+
+/// Positions of the per-NFT accounts in the `ForceUnstake` account list: the `Unstake` ones
+/// but the metadata and whitelist, which it doesn't read, the owner's reward holder and
+/// staker account, and the owner
+pub const FORCE_UNSTAKE_GROUP: [usize; 10] = [2, 6, 8, 10, 11, 18, 19, 21, 22, 23];
+
+/// Stands in for the accounts an NFT of a batch doesn't use, like the edition of a position
+/// that isn't frozen. Every list has it already, so the message doesn't grow by another key
+pub fn unused_account() -> AccountMeta {
+    AccountMeta::new_readonly(system_program::id(), false)
+}
+
+/// Splits the accounts of a batch into the account lists of its single NFT instructions,
+/// the shared accounts come once in their usual order followed by `count` groups
+pub fn split_batch<T: Clone>(
+    accounts: &[T],
+    size: usize,
+    group: &[usize],
+    count: usize,
+) -> Result<Vec<Vec<T>>, ProgramError> {
+    let shared_len = size - group.len();
+
+    if count == 0 || accounts.len() != shared_len + count * group.len() {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    let (shared, groups) = accounts.split_at(shared_len);
+
+    Ok(groups
+        .chunks(group.len())
+        .map(|nft_accounts| {
+            let mut shared = shared.iter();
+
+            (0..size)
+                .map(|i| match group.iter().position(|g| *g == i) {
+                    Some(g) => nft_accounts[g].clone(),
+                    None => shared.next().unwrap().clone(),
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Inverse of `split_batch`, the shared accounts are taken from the first list
+pub fn join_batch<T: Clone>(lists: &[Vec<T>], group: &[usize]) -> Vec<T> {
+    let mut accounts: Vec<T> = lists[0]
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !group.contains(i))
+        .map(|(_, account)| account.clone())
+        .collect();
+
+    for list in lists {
+        accounts.extend(group.iter().map(|g| list[*g].clone()));
+    }
+
+    accounts
+}
@@ -1,20 +1,113 @@
 use crate::consts::VAULT;
-use crate::processor::staking::claim::Accounts;
+use crate::error::ContractError;
+use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::Account as TokenAccount;
 
-pub fn claim_transfer(accounts: &Accounts, vault_bump: u8, reward: u64) -> ProgramResult {
+/// Accounts paying a reward from the vault to the owner, or to `recipient_info` when set
+pub struct RewardAccounts<'a, 'b> {
+    pub payer: &'a AccountInfo<'b>,
+    /// Wallet of `payer_reward_holder_info`, the payer except in `ForceUnstake`
+    pub owner: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    pub token_info: &'a AccountInfo<'b>,
+    pub rent_info: &'a AccountInfo<'b>,
+    pub assoc_acccount_info: &'a AccountInfo<'b>,
+    pub vault_info: &'a AccountInfo<'b>,
+    pub payer_reward_holder_info: &'a AccountInfo<'b>,
+    pub vault_reward_holder_info: &'a AccountInfo<'b>,
+    pub reward_mint_info: &'a AccountInfo<'b>,
+    pub pool_info: &'a AccountInfo<'b>,
+    pub recipient_info: Option<&'a AccountInfo<'b>>,
+}
+
+/// A recipient has to be an existing token account of the reward mint, its owner doesn't matter
+pub fn check_recipient(
+    recipient_info: &AccountInfo,
+    token_info: &AccountInfo,
+    reward_mint: &Pubkey,
+) -> ProgramResult {
+    if recipient_info.owner != token_info.key {
+        return Err(ContractError::InvalidRecipient.into());
+    }
+
+    let recipient = match TokenAccount::unpack(&recipient_info.data.borrow()) {
+        Ok(recipient) => recipient,
+        Err(_) => return Err(ContractError::InvalidRecipient.into()),
+    };
+
+    if recipient.mint != *reward_mint {
+        return Err(ContractError::InvalidRecipient.into());
+    }
+
+    Ok(())
+}
+
+/// Reward tokens in the vault, a missing reward ATA holds none
+pub fn vault_balance(accounts: &RewardAccounts) -> Result<u64, ProgramError> {
+    if accounts.vault_reward_holder_info.owner != accounts.token_info.key {
+        return Ok(0);
+    }
+
+    match TokenAccount::unpack(&accounts.vault_reward_holder_info.data.borrow()) {
+        Ok(vault_rewards) => Ok(vault_rewards.amount),
+        Err(_) => Err(ContractError::InvalidInstructionData.into()),
+    }
+}
+
+pub fn claim_transfer(accounts: &RewardAccounts, vault_bump: u8, reward: u64) -> ProgramResult {
+    let destination_info = match accounts.recipient_info {
+        Some(recipient_info) => {
+            check_recipient(
+                recipient_info,
+                accounts.token_info,
+                accounts.reward_mint_info.key,
+            )?;
+            recipient_info
+        }
+        None => {
+            create_reward_holder(accounts)?;
+            accounts.payer_reward_holder_info
+        }
+    };
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            accounts.token_info.key,
+            accounts.vault_reward_holder_info.key,
+            destination_info.key,
+            accounts.vault_info.key,
+            &[],
+            reward,
+        )?,
+        &[
+            accounts.vault_reward_holder_info.clone(),
+            destination_info.clone(),
+            accounts.vault_info.clone(),
+            accounts.token_info.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    Ok(())
+}
+
+fn create_reward_holder(accounts: &RewardAccounts) -> ProgramResult {
     if accounts.payer_reward_holder_info.owner != accounts.token_info.key {
         invoke(
             &spl_associated_token_account::create_associated_token_account(
                 accounts.payer.key,
-                accounts.payer.key,
+                accounts.owner.key,
                 accounts.reward_mint_info.key,
             ),
             &[
                 accounts.payer.clone(),
                 accounts.payer_reward_holder_info.clone(),
-                accounts.payer.clone(),
+                accounts.owner.clone(),
                 accounts.reward_mint_info.clone(),
                 accounts.system_program.clone(),
                 accounts.token_info.clone(),
@@ -24,23 +117,5 @@ pub fn claim_transfer(accounts: &Accounts, vault_bump: u8, reward: u64) -> Progr
         )?;
     }
 
-    invoke_signed(
-        &spl_token::instruction::transfer(
-            accounts.token_info.key,
-            accounts.vault_reward_holder_info.key,
-            accounts.payer_reward_holder_info.key,
-            accounts.vault_info.key,
-            &[],
-            reward,
-        )?,
-        &[
-            accounts.vault_reward_holder_info.clone(),
-            accounts.payer_reward_holder_info.clone(),
-            accounts.vault_info.clone(),
-            accounts.token_info.clone(),
-        ],
-        &[&[VAULT, &[vault_bump]]],
-    )?;
-
     Ok(())
 }
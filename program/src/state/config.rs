@@ -0,0 +1,92 @@
+use crate::consts::CONFIG;
+use crate::error::ContractError;
+use crate::state::structs::{PauseFlags, PlatformConfig};
+use solana_program::account_info::AccountInfo;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::bpf_loader_upgradeable;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn get_platform_config(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+) -> Result<PlatformConfig, ProgramError> {
+    let (config_address, _) = Pubkey::find_program_address(&[CONFIG], program_id);
+
+    if *config_info.key != config_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if config_info.owner != program_id {
+        return Err(ContractError::NotInitialized.into());
+    }
+
+    if let Ok(data) = try_from_slice_unchecked::<PlatformConfig>(&config_info.data.borrow()) {
+        Ok(data)
+    } else {
+        Err(ContractError::DeserializeError.into())
+    }
+}
+
+pub fn check_admin(config: &PlatformConfig, payer: &AccountInfo) -> ProgramResult {
+    if *payer.key != config.admin || !payer.is_signer {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    Ok(())
+}
+
+/// Looks the config up by address, so that paused operations are rejected
+/// before their own accounts are parsed
+pub fn check_not_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    is_paused: fn(&PauseFlags) -> bool,
+) -> ProgramResult {
+    let (config_address, _) = Pubkey::find_program_address(&[CONFIG], program_id);
+
+    let config_info = match accounts.iter().find(|info| *info.key == config_address) {
+        Some(info) => info,
+        None => return Err(ContractError::InvalidInstructionData.into()),
+    };
+
+    let config = get_platform_config(program_id, config_info)?;
+
+    if is_paused(&config.paused) {
+        return Err(ContractError::Paused.into());
+    }
+
+    Ok(())
+}
+
+/// Only the upgrade authority of the deployed program may initialize it,
+/// otherwise anyone could front-run the deployer and take over the config
+pub fn check_upgrade_authority(
+    program_id: &Pubkey,
+    program_data_info: &AccountInfo,
+    payer: &AccountInfo,
+) -> ProgramResult {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+    if *program_data_info.key != program_data_address
+        || *program_data_info.owner != bpf_loader_upgradeable::id()
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    // UpgradeableLoaderState::ProgramData is bincode encoded as
+    // u32 variant (3) | u64 slot | Option<Pubkey> upgrade authority
+    let data = program_data_info.data.borrow();
+
+    if data.len() < 45 || data[0..4] != [3, 0, 0, 0] || data[12] != 1 {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    if data[13..45] != payer.key.to_bytes() || !payer.is_signer {
+        return Err(ContractError::UnauthorisedAccess.into());
+    }
+
+    Ok(())
+}
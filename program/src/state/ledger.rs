@@ -0,0 +1,68 @@
+use crate::state::reward_calculation::{apply_multiplier, stake_multiplier};
+use crate::state::structs::{RewardLedger, StakeData, StakerData};
+
+/// Deposited rewards that were not paid out or withdrawn yet
+pub fn funded(ledger: &RewardLedger) -> u64 {
+    ledger
+        .total_deposited
+        .saturating_sub(ledger.total_paid)
+        .saturating_sub(ledger.total_withdrawn)
+}
+
+/// Part of the vault balance not owed to any position
+pub fn surplus(ledger: &RewardLedger, vault_balance: u64) -> u64 {
+    vault_balance.saturating_sub(ledger.liability)
+}
+
+/// Part of a reward an underfunded vault can pay right now, the rest is owed to the staker
+pub fn payable(reward: u64, vault_balance: u64) -> u64 {
+    reward.min(vault_balance)
+}
+
+/// Reserves the maximum payout of a new position in place of what its previous one reserved
+pub fn reserve(ledger: &mut RewardLedger, stake_data: &mut StakeData, reserved: u64) {
+    ledger.liability = ledger.liability.saturating_sub(stake_data.reserved) + reserved;
+    ledger.active_multiplier += stake_multiplier(stake_data);
+    stake_data.reserved = reserved;
+    stake_data.raised_at = ledger.raised;
+}
+
+/// Moves the position's share of the cap raises since it last settled into its reservation,
+/// the liability already counts it
+pub fn settle_raises(ledger: &RewardLedger, stake_data: &mut StakeData) {
+    stake_data.reserved += apply_multiplier(
+        ledger.raised - stake_data.raised_at,
+        stake_multiplier(stake_data),
+    );
+    stake_data.raised_at = ledger.raised;
+}
+
+/// Records a payout to the position
+pub fn record_payout(ledger: &mut RewardLedger, stake_data: &mut StakeData, amount: u64) {
+    settle_raises(ledger, stake_data);
+
+    let released = amount.min(stake_data.reserved);
+
+    stake_data.reserved -= released;
+    ledger.liability = ledger.liability.saturating_sub(released);
+    ledger.total_paid += amount;
+}
+
+/// Releases what an unstaked position can no longer earn, the `owed` part of its reward
+/// stays in the liability until the staker claims it
+pub fn release(ledger: &mut RewardLedger, stake_data: &mut StakeData, owed: u64) {
+    settle_raises(ledger, stake_data);
+
+    ledger.liability = ledger.liability.saturating_sub(stake_data.reserved) + owed;
+    ledger.active_multiplier = ledger
+        .active_multiplier
+        .saturating_sub(stake_multiplier(stake_data));
+    stake_data.reserved = 0;
+}
+
+/// Records a payout of the reward owed to the staker
+pub fn record_debt_payout(ledger: &mut RewardLedger, staker: &mut StakerData, amount: u64) {
+    staker.owed -= amount;
+    ledger.liability = ledger.liability.saturating_sub(amount);
+    ledger.total_paid += amount;
+}
@@ -0,0 +1,24 @@
+use solana_program::keccak::hashv;
+use solana_program::pubkey::Pubkey;
+
+/// Leaf of the boost tree, prefixed so a leaf can't pass for an inner node
+pub fn boost_leaf(mint: &Pubkey, boost: u64) -> [u8; 32] {
+    hashv(&[&[0], &mint.to_bytes(), &boost.to_le_bytes()]).to_bytes()
+}
+
+/// Inner node of the boost tree, children are hashed in sorted order
+pub fn boost_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        hashv(&[&[1], left, right]).to_bytes()
+    } else {
+        hashv(&[&[1], right, left]).to_bytes()
+    }
+}
+
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| boost_node(&node, sibling));
+
+    computed == *root
+}
@@ -0,0 +1,52 @@
+use crate::error::ContractError;
+use crate::state::structs::Eligibility;
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_token_metadata::state::Metadata;
+
+/// `collection` field of token-metadata v1.1+, not known to `spl_token_metadata` 0.0.1
+#[derive(BorshDeserialize)]
+struct Collection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// Verified collection mint, read from the fields that follow `Metadata`:
+/// `Option<TokenStandard>` and `Option<Collection>`
+pub fn get_verified_collection(metadata_data: &[u8]) -> Option<Pubkey> {
+    let mut data = metadata_data;
+
+    Metadata::deserialize(&mut data).ok()?;
+    Option::<u8>::deserialize(&mut data).ok()?;
+
+    match Option::<Collection>::deserialize(&mut data).ok()? {
+        Some(collection) if collection.verified => Some(collection.key),
+        _ => None,
+    }
+}
+
+/// Address the whitelist entry of an NFT is derived from: its first creator
+/// or its collection mint, both have to be verified
+pub fn get_whitelist_key(
+    metadata_data: &[u8],
+    eligibility: Eligibility,
+) -> Result<Pubkey, ProgramError> {
+    match eligibility {
+        Eligibility::Creator => {
+            let metadata = match Metadata::deserialize(&mut &metadata_data[..]) {
+                Ok(metadata) => metadata,
+                Err(_) => return Err(ContractError::DeserializeError.into()),
+            };
+
+            match metadata.data.creators.as_ref().and_then(|c| c.first()) {
+                Some(creator) if creator.verified => Ok(creator.address),
+                _ => Err(ContractError::UnverifiedAddress.into()),
+            }
+        }
+        Eligibility::Collection => match get_verified_collection(metadata_data) {
+            Some(collection) => Ok(collection),
+            None => Err(ContractError::UnverifiedAddress.into()),
+        },
+    }
+}
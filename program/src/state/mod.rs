@@ -1,4 +1,17 @@
+pub mod batch;
 pub mod claim;
+pub mod config;
+pub mod ledger;
+pub mod merkle;
+pub mod metadata;
+pub mod pda;
+pub mod pool;
+pub mod receipt;
 pub mod reward_calculation;
+pub mod soft_stake;
 pub mod stake;
+pub mod staker;
+pub mod stats;
 pub mod structs;
+pub mod unstake;
+pub mod whitelist;
@@ -0,0 +1,51 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+
+/// Empties a PDA and sends its rent to `receiver`, the runtime removes it after the transaction
+pub fn close_pda_account(pda: &AccountInfo, receiver: &AccountInfo) {
+    let lamports = pda.lamports();
+
+    **receiver.lamports.borrow_mut() += lamports;
+    **pda.lamports.borrow_mut() = 0;
+
+    pda.data.borrow_mut().fill(0);
+}
+
+/// Funds, allocates and assigns a PDA to `program_id`, the program itself or spl-token for mints
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    pda: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    rent: &Rent,
+    size: usize,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    let required_lamports = rent
+        .minimum_balance(size)
+        .max(1)
+        .saturating_sub(pda.lamports());
+
+    invoke(
+        &system_instruction::transfer(payer.key, pda.key, required_lamports),
+        &[payer.clone(), pda.clone(), system_program.clone()],
+    )?;
+
+    invoke_signed(
+        &system_instruction::allocate(pda.key, size as u64),
+        &[pda.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::assign(pda.key, program_id),
+        &[pda.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    Ok(())
+}
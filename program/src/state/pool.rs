@@ -0,0 +1,181 @@
+use crate::consts::{BASIS_POINTS, MAX_LOCK_TIERS, MAX_SCHEDULE_EPOCHS, POOL, VAULT};
+use crate::error::ContractError;
+use crate::state::ledger::funded;
+use crate::state::reward_calculation::apply_multiplier;
+use crate::state::structs::{
+    LockTier, Pool, PoolOptions, RewardLedger, RewardSchedule, ScheduleEpoch, StakeData,
+};
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn get_pool(
+    program_id: &Pubkey,
+    pool_info: &AccountInfo,
+    pool_id: u64,
+) -> Result<Pool, ProgramError> {
+    let (pool_address, _) =
+        Pubkey::find_program_address(&[POOL, &pool_id.to_le_bytes()], program_id);
+
+    if *pool_info.key != pool_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if pool_info.owner != program_id {
+        return Err(ContractError::PoolNotFound.into());
+    }
+
+    if let Ok(data) = try_from_slice_unchecked::<Pool>(&pool_info.data.borrow()) {
+        Ok(data)
+    } else {
+        Err(ContractError::DeserializeError.into())
+    }
+}
+
+pub fn get_vault_address(
+    program_id: &Pubkey,
+    pool_address: &Pubkey,
+    pool: &Pool,
+) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_program_address(
+        &[VAULT, &pool_address.to_bytes(), &[pool.vault_bump]],
+        program_id,
+    )?)
+}
+
+pub fn check_schedule(schedule: &RewardSchedule) -> ProgramResult {
+    if schedule.interval == 0 || schedule.warmup_intervals >= schedule.period_intervals {
+        return Err(ContractError::InvalidSchedule.into());
+    }
+
+    Ok(())
+}
+
+/// Positions reserve the cap they were staked under, a raise reserves the increase for every
+/// active position as well and is refused unless the funded surplus covers it
+pub fn raise_cap(pool: &mut Pool, schedule: &RewardSchedule) -> ProgramResult {
+    let current = current_schedule(pool).max_payout_per_nft;
+
+    if schedule.max_payout_per_nft <= current {
+        return Ok(());
+    }
+
+    let raise = schedule.max_payout_per_nft - current;
+    let reserved = apply_multiplier(raise, pool.ledger.active_multiplier);
+
+    if reserved > funded(&pool.ledger).saturating_sub(pool.ledger.liability) {
+        return Err(ContractError::InsufficientRewards.into());
+    }
+
+    pool.ledger.liability += reserved;
+    pool.ledger.raised += raise;
+
+    Ok(())
+}
+
+pub fn check_lock_tiers(tiers: &[LockTier]) -> ProgramResult {
+    if tiers.len() > MAX_LOCK_TIERS {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if tiers.iter().any(|tier| tier.multiplier == 0) {
+        return Err(ContractError::InvalidMultiplier.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_pool_options(options: &PoolOptions) -> ProgramResult {
+    // a soft staked NFT is frozen in the staker's wallet and can't follow the receipt
+    if options.soft_staking && options.mint_receipts {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    match options.early_exit_penalty {
+        Some(penalty) if penalty > BASIS_POINTS => {
+            Err(ContractError::InvalidInstructionData.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Lock-up end and multiplier of the tier a stake chose, open-ended stakes aren't boosted
+pub fn lock_terms(
+    pool: &Pool,
+    lock_tier: Option<u8>,
+    now: u64,
+) -> Result<(u64, u64), ProgramError> {
+    match lock_tier {
+        Some(index) => match pool.lock_tiers.get(index as usize) {
+            Some(tier) => Ok((now + tier.duration, tier.multiplier)),
+            None => Err(ContractError::InvalidLockTier.into()),
+        },
+        None => Ok((0, BASIS_POINTS)),
+    }
+}
+
+/// Reward paid when unstaking at `now` with `earned` since the checkpoint, a locked position
+/// forfeits the early exit penalty on it. What was already owed in `accrued` is kept in full
+pub fn unlocked_reward(
+    options: &PoolOptions,
+    stake_data: &StakeData,
+    earned: u64,
+    now: u64,
+) -> Result<u64, ProgramError> {
+    if now >= stake_data.lock_until {
+        return Ok(stake_data.accrued + earned);
+    }
+
+    match options.early_exit_penalty {
+        Some(penalty) => Ok(stake_data.accrued + earned - apply_multiplier(earned, penalty)),
+        None => Err(ContractError::StillLocked.into()),
+    }
+}
+
+/// Locked positions are only paid out when they leave, the early exit penalty can't be
+/// skipped by claiming first
+pub fn check_unlocked(stake_data: &StakeData, now: u64) -> ProgramResult {
+    if stake_data.active && now < stake_data.lock_until {
+        return Err(ContractError::StillLocked.into());
+    }
+
+    Ok(())
+}
+
+/// Account size of a pool with a full schedule history
+pub fn pool_size() -> usize {
+    let epoch = ScheduleEpoch {
+        start: 0,
+        schedule: RewardSchedule::default(),
+    };
+
+    Pool {
+        pool_id: 0,
+        reward_mint: Pubkey::default(),
+        vault_bump: 0,
+        boost_root: Some([0; 32]),
+        options: PoolOptions {
+            early_exit_penalty: Some(0),
+            ..PoolOptions::default()
+        },
+        ledger: RewardLedger::default(),
+        lock_tiers: vec![
+            LockTier {
+                duration: 0,
+                multiplier: 0,
+            };
+            MAX_LOCK_TIERS
+        ],
+        epochs: vec![epoch; MAX_SCHEDULE_EPOCHS],
+    }
+    .try_to_vec()
+    .unwrap()
+    .len()
+}
+
+pub fn current_schedule(pool: &Pool) -> &RewardSchedule {
+    &pool.epochs.last().unwrap().schedule
+}
@@ -0,0 +1,242 @@
+use crate::consts::{RECEIPT, VAULT};
+use crate::error::ContractError;
+use crate::processor::staking::claim::Accounts as ClaimAccounts;
+use crate::processor::staking::stake::Accounts as StakeAccounts;
+use crate::state::pda::create_pda_account;
+use crate::state::structs::StakeData;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use spl_token::state::{Account as TokenAccount, Mint};
+
+/// Receipt mint of the `stakes`-th position of the NFT, see `StakeData::stakes`
+pub fn get_receipt_address(
+    program_id: &Pubkey,
+    pool: &Pubkey,
+    mint: &Pubkey,
+    stakes: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            RECEIPT,
+            &pool.to_bytes(),
+            &mint.to_bytes(),
+            &stakes.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Mints the 1-of-1 receipt of a position to the staker, the vault is its mint and freeze authority.
+/// Every stake of the NFT gets a new mint, receipts of earlier positions control nothing. A receipt
+/// can't be burned without its holder, so a shared mint would leave the receipt of a force-unstaked
+/// position valid for the next one. The cost is a mint account per stake paid by the staker,
+/// spl-token can't close mints so its rent stays locked
+pub fn mint_receipt(
+    accounts: &StakeAccounts,
+    program_id: &Pubkey,
+    rent: &Rent,
+    vault_bump: u8,
+    stakes: u64,
+) -> ProgramResult {
+    let (receipt_address, receipt_bump) = get_receipt_address(
+        program_id,
+        accounts.pool_info.key,
+        accounts.mint.key,
+        stakes,
+    );
+
+    let receipt_holder = spl_associated_token_account::get_associated_token_address(
+        accounts.payer.key,
+        &receipt_address,
+    );
+
+    if receipt_address != *accounts.receipt_mint_info.key
+        || receipt_holder != *accounts.receipt_holder_info.key
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if accounts.receipt_mint_info.owner != accounts.token_program.key {
+        create_pda_account(
+            accounts.payer,
+            accounts.receipt_mint_info,
+            accounts.sys_info,
+            accounts.token_program.key,
+            rent,
+            Mint::LEN,
+            &[
+                RECEIPT,
+                &accounts.pool_info.key.to_bytes(),
+                &accounts.mint.key.to_bytes(),
+                &stakes.to_le_bytes(),
+                &[receipt_bump],
+            ],
+        )?;
+
+        invoke(
+            &spl_token::instruction::initialize_mint(
+                accounts.token_program.key,
+                accounts.receipt_mint_info.key,
+                accounts.vault_info.key,
+                Some(accounts.vault_info.key),
+                0,
+            )?,
+            &[
+                accounts.receipt_mint_info.clone(),
+                accounts.rent_info.clone(),
+                accounts.token_program.clone(),
+            ],
+        )?;
+    }
+
+    if accounts.receipt_holder_info.owner != accounts.token_program.key {
+        invoke(
+            &spl_associated_token_account::create_associated_token_account(
+                accounts.payer.key,
+                accounts.payer.key,
+                accounts.receipt_mint_info.key,
+            ),
+            &[
+                accounts.payer.clone(),
+                accounts.receipt_holder_info.clone(),
+                accounts.payer.clone(),
+                accounts.receipt_mint_info.clone(),
+                accounts.sys_info.clone(),
+                accounts.token_program.clone(),
+                accounts.rent_info.clone(),
+                accounts.token_assoc.clone(),
+            ],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            accounts.token_program.key,
+            accounts.receipt_mint_info.key,
+            accounts.receipt_holder_info.key,
+            accounts.vault_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            accounts.receipt_mint_info.clone(),
+            accounts.receipt_holder_info.clone(),
+            accounts.vault_info.clone(),
+            accounts.token_program.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    Ok(())
+}
+
+/// Receipt positions belong to whoever holds the receipt, the others to `StakeData::staker`.
+/// The owner is the payer, or the wallet the NFT goes back to in `ForceUnstake`
+pub fn check_position_owner(
+    accounts: &ClaimAccounts,
+    program_id: &Pubkey,
+    stake_data: &StakeData,
+) -> ProgramResult {
+    let owner = accounts.owner();
+
+    if !stake_data.receipt {
+        if stake_data.staker != *owner.key {
+            return Err(ContractError::UnauthorisedAccess.into());
+        }
+
+        return Ok(());
+    }
+
+    let (receipt_address, _) = get_receipt_address(
+        program_id,
+        accounts.pool_info.key,
+        accounts.nft_info.key,
+        stake_data.stakes,
+    );
+
+    if receipt_address != *accounts.receipt_mint_info.key
+        || accounts.receipt_holder_info.owner != accounts.token_info.key
+    {
+        return Err(ContractError::InvalidReceipt.into());
+    }
+
+    let holder = match TokenAccount::unpack(&accounts.receipt_holder_info.data.borrow()) {
+        Ok(holder) => holder,
+        Err(_) => return Err(ContractError::InvalidReceipt.into()),
+    };
+
+    if holder.mint != receipt_address
+        || holder.owner != *owner.key
+        || holder.amount != 1
+        || holder.is_frozen()
+    {
+        return Err(ContractError::InvalidReceipt.into());
+    }
+
+    Ok(())
+}
+
+/// Burns the receipt of a position leaving the pool and closes the holder's token account,
+/// spl-token can't close mints so the empty receipt mint stays behind
+pub fn burn_receipt(accounts: &ClaimAccounts) -> ProgramResult {
+    invoke(
+        &spl_token::instruction::burn(
+            accounts.token_info.key,
+            accounts.receipt_holder_info.key,
+            accounts.receipt_mint_info.key,
+            accounts.payer.key,
+            &[],
+            1,
+        )?,
+        &[
+            accounts.receipt_holder_info.clone(),
+            accounts.receipt_mint_info.clone(),
+            accounts.payer.clone(),
+            accounts.token_info.clone(),
+        ],
+    )?;
+
+    invoke(
+        &spl_token::instruction::close_account(
+            accounts.token_info.key,
+            accounts.receipt_holder_info.key,
+            accounts.payer.key,
+            accounts.payer.key,
+            &[],
+        )?,
+        &[
+            accounts.receipt_holder_info.clone(),
+            accounts.payer.clone(),
+            accounts.payer.clone(),
+            accounts.token_info.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Freezes the receipt of a force-unstaked position in the holder's wallet, only the holder could
+/// burn it, frozen it can't be sold on as a claim on the NFT
+pub fn freeze_receipt(accounts: &ClaimAccounts, vault_bump: u8) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::freeze_account(
+            accounts.token_info.key,
+            accounts.receipt_holder_info.key,
+            accounts.receipt_mint_info.key,
+            accounts.vault_info.key,
+            &[],
+        )?,
+        &[
+            accounts.receipt_holder_info.clone(),
+            accounts.receipt_mint_info.clone(),
+            accounts.vault_info.clone(),
+            accounts.token_info.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    Ok(())
+}
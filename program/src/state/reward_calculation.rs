@@ -1,29 +1,75 @@
-use crate::consts::{MAX_PAYOUT_PER_NFT, PAYOUT_PER_DAY, SECONDS_IN_THE_DAY};
+use crate::consts::BASIS_POINTS;
+use crate::state::structs::{RewardSchedule, ScheduleEpoch, StakeData};
 
+/// Total reward of a stake that is `staked_for` seconds old under `schedule`
+pub fn cumulative_reward(schedule: &RewardSchedule, staked_for: u64) -> u64 {
+    let periods = staked_for / schedule.interval;
+
+    if periods <= schedule.warmup_intervals {
+        0
+    } else if periods <= schedule.period_intervals {
+        let paid = (periods - schedule.warmup_intervals) as u128;
+        let reward = schedule.payout_per_interval as u128 * paid * (paid + 1) / 2;
+        reward.min(schedule.max_payout_per_nft as u128) as u64
+    } else {
+        schedule.max_payout_per_nft
+    }
+}
+
+/// `amount` scaled by a multiplier in basis points
+pub fn apply_multiplier(amount: u64, multiplier: u64) -> u64 {
+    (amount as u128 * multiplier as u128 / BASIS_POINTS as u128) as u64
+}
+
+/// Whitelist multiplier of a stake combined with its rarity boost and lock tier
+pub fn stake_multiplier(stake_data: &StakeData) -> u64 {
+    apply_multiplier(
+        apply_multiplier(stake_data.reward_multiplier, stake_data.boost),
+        stake_data.lock_multiplier,
+    )
+}
+
+/// Reward accrued between `checkpoint` and `clock_timestamp`.
+///
+/// Every epoch only prices the part of the stake that falls inside it, so a schedule
+/// change never touches what was accrued before. `earned` is the reward the NFT has
+/// already earned and counts towards the per-NFT cap of each epoch. `multiplier`
+/// scales both the payout and the cap.
 pub fn calculate_reward(
+    epochs: &[ScheduleEpoch],
     clock_timestamp: u64,
     stake_timestamp: u64,
-    harvested: u64,
-    withdrawn: u64,
+    checkpoint: u64,
+    earned: u64,
+    multiplier: u64,
 ) -> u64 {
-    let periods = (clock_timestamp - stake_timestamp) / SECONDS_IN_THE_DAY;
-
-    let mut reward = match periods {
-        0..=1 => 0,
-        2..=180 => {
-            let mut reward = 0;
-            for day in 2..=periods {
-                reward += PAYOUT_PER_DAY * (day - 1);
-            }
-            reward
+    let mut earned = earned;
+    let mut reward = 0;
+
+    for (i, epoch) in epochs.iter().enumerate() {
+        let end = match epochs.get(i + 1) {
+            Some(next) => next.start.min(clock_timestamp),
+            None => clock_timestamp,
+        };
+        let start = epoch.start.max(checkpoint).max(stake_timestamp);
+
+        if start >= end {
+            continue;
         }
-        _ => MAX_PAYOUT_PER_NFT,
-    };
 
-    reward -= withdrawn;
+        let schedule = &epoch.schedule;
+        let accrued = apply_multiplier(
+            cumulative_reward(schedule, end - stake_timestamp),
+            multiplier,
+        )
+        .saturating_sub(apply_multiplier(
+            cumulative_reward(schedule, start - stake_timestamp),
+            multiplier,
+        ))
+        .min(apply_multiplier(schedule.max_payout_per_nft, multiplier).saturating_sub(earned));
 
-    if reward >= (MAX_PAYOUT_PER_NFT - harvested) {
-        return MAX_PAYOUT_PER_NFT - harvested;
+        earned += accrued;
+        reward += accrued;
     }
 
     reward
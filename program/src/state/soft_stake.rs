@@ -0,0 +1,158 @@
+use crate::consts::VAULT;
+use crate::error::ContractError;
+use crate::processor::staking::claim::Accounts as ClaimAccounts;
+use crate::processor::staking::stake::Accounts as StakeAccounts;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::pubkey::Pubkey;
+
+/// Token-metadata instructions letting the delegate of a token account freeze and thaw it
+pub const FREEZE_DELEGATED_ACCOUNT: u8 = 26;
+pub const THAW_DELEGATED_ACCOUNT: u8 = 27;
+
+pub fn get_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            "metadata".as_bytes(),
+            &spl_token_metadata::ID.to_bytes(),
+            &mint.to_bytes(),
+            "edition".as_bytes(),
+        ],
+        &spl_token_metadata::ID,
+    )
+    .0
+}
+
+/// The master edition is the freeze authority of the mint, token-metadata uses it for the delegate
+pub fn check_edition_accounts(
+    mint: &AccountInfo,
+    edition_info: &AccountInfo,
+    metadata_program_info: &AccountInfo,
+) -> ProgramResult {
+    if get_edition_address(mint.key) != *edition_info.key
+        || *metadata_program_info.key != spl_token_metadata::ID
+    {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    Ok(())
+}
+
+/// The delegate is a writable signer, so the vault has to be writable in the instructions calling it
+pub fn delegated_account_instruction(
+    tag: u8,
+    delegate: &Pubkey,
+    token_account: &Pubkey,
+    edition: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: spl_token_metadata::ID,
+        accounts: vec![
+            AccountMeta::new(*delegate, true),
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new_readonly(*edition, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: vec![tag],
+    }
+}
+
+/// Approves the vault as delegate of the staker's token account and freezes it in place
+pub fn freeze_nft(accounts: &StakeAccounts, vault_bump: u8) -> ProgramResult {
+    check_edition_accounts(
+        accounts.mint,
+        accounts.edition_info,
+        accounts.metadata_program_info,
+    )?;
+
+    invoke(
+        &spl_token::instruction::approve(
+            accounts.token_program.key,
+            accounts.source.key,
+            accounts.vault_info.key,
+            accounts.payer.key,
+            &[],
+            1,
+        )?,
+        &[
+            accounts.source.clone(),
+            accounts.vault_info.clone(),
+            accounts.payer.clone(),
+            accounts.token_program.clone(),
+        ],
+    )?;
+
+    invoke_signed(
+        &delegated_account_instruction(
+            FREEZE_DELEGATED_ACCOUNT,
+            accounts.vault_info.key,
+            accounts.source.key,
+            accounts.edition_info.key,
+            accounts.mint.key,
+        ),
+        &[
+            accounts.vault_info.clone(),
+            accounts.source.clone(),
+            accounts.edition_info.clone(),
+            accounts.mint.clone(),
+            accounts.token_program.clone(),
+            accounts.metadata_program_info.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    Ok(())
+}
+
+/// Thaws the staker's token account and revokes the vault delegate
+pub fn thaw_nft(accounts: &ClaimAccounts, vault_bump: u8) -> ProgramResult {
+    check_edition_accounts(
+        accounts.nft_info,
+        accounts.edition_info,
+        accounts.metadata_program_info,
+    )?;
+
+    invoke_signed(
+        &delegated_account_instruction(
+            THAW_DELEGATED_ACCOUNT,
+            accounts.vault_info.key,
+            accounts.payer_nft_holder_info.key,
+            accounts.edition_info.key,
+            accounts.nft_info.key,
+        ),
+        &[
+            accounts.vault_info.clone(),
+            accounts.payer_nft_holder_info.clone(),
+            accounts.edition_info.clone(),
+            accounts.nft_info.clone(),
+            accounts.token_info.clone(),
+            accounts.metadata_program_info.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    // a forced unstake can't sign for the owner, the vault stays its delegate until they revoke it
+    if accounts.owner_info.is_some() {
+        return Ok(());
+    }
+
+    invoke(
+        &spl_token::instruction::revoke(
+            accounts.token_info.key,
+            accounts.payer_nft_holder_info.key,
+            accounts.payer.key,
+            &[],
+        )?,
+        &[
+            accounts.payer_nft_holder_info.clone(),
+            accounts.payer.clone(),
+            accounts.token_info.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
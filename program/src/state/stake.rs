@@ -1,51 +1,37 @@
 use crate::error::ContractError;
 use crate::processor::staking::stake::Accounts;
+use crate::state::pda::create_pda_account;
 use crate::state::structs::StakeData;
 use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
+use solana_program::borsh::get_packed_len;
 use solana_program::entrypoint::ProgramResult;
-use solana_program::program::{invoke, invoke_signed};
+use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
-use solana_program::system_instruction;
 use std::cell::Ref;
 
 pub fn pay_rent(
     accounts: &Accounts,
     program_id: &Pubkey,
     rent: &Rent,
-    stake_data: Pubkey,
     stake_data_bump: u8,
 ) -> ProgramResult {
     if accounts.stake_data_info.owner != program_id {
-        let size: u64 = 8 + 32 + 32 + 8 + 1 + 8;
-
-        let required_lamports = rent
-            .minimum_balance(size as usize)
-            .max(1)
-            .saturating_sub(accounts.stake_data_info.lamports());
-
-        invoke(
-            &system_instruction::transfer(accounts.payer.key, &stake_data, required_lamports),
+        create_pda_account(
+            accounts.payer,
+            accounts.stake_data_info,
+            accounts.sys_info,
+            program_id,
+            rent,
+            get_packed_len::<StakeData>(),
             &[
-                accounts.payer.clone(),
-                accounts.stake_data_info.clone(),
-                accounts.sys_info.clone(),
+                &accounts.pool_info.key.to_bytes(),
+                &accounts.mint.key.to_bytes(),
+                &[stake_data_bump],
             ],
         )?;
-
-        invoke_signed(
-            &system_instruction::allocate(&stake_data, size),
-            &[accounts.stake_data_info.clone(), accounts.sys_info.clone()],
-            &[&[&accounts.mint.key.to_bytes(), &[stake_data_bump]]],
-        )?;
-
-        invoke_signed(
-            &system_instruction::assign(&stake_data, program_id),
-            &[accounts.stake_data_info.clone(), accounts.sys_info.clone()],
-            &[&[&accounts.mint.key.to_bytes(), &[stake_data_bump]]],
-        )?;
     }
 
     Ok(())
@@ -0,0 +1,128 @@
+use crate::consts::{MAX_STAKER_POSITIONS, STAKER};
+use crate::error::ContractError;
+use crate::state::pda::{close_pda_account, create_pda_account};
+use crate::state::structs::StakerData;
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+pub fn get_staker_address(program_id: &Pubkey, pool: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKER, &pool.to_bytes(), &wallet.to_bytes()], program_id)
+}
+
+/// Account size of the staker with all positions taken
+pub fn staker_size() -> usize {
+    StakerData {
+        mints: vec![Pubkey::default(); MAX_STAKER_POSITIONS],
+        ..StakerData::default()
+    }
+    .try_to_vec()
+    .unwrap()
+    .len()
+}
+
+/// Creates the staker account of `wallet` on its first position in the pool, funded by `payer`
+pub fn create_staker_account<'a>(
+    payer: &AccountInfo<'a>,
+    wallet: &AccountInfo<'a>,
+    staker_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    rent: &Rent,
+    pool: &Pubkey,
+) -> ProgramResult {
+    let (staker_address, staker_bump) = get_staker_address(program_id, pool, wallet.key);
+
+    if *staker_info.key != staker_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if staker_info.owner != program_id {
+        create_pda_account(
+            payer,
+            staker_info,
+            system_program,
+            program_id,
+            rent,
+            staker_size(),
+            &[
+                STAKER,
+                &pool.to_bytes(),
+                &wallet.key.to_bytes(),
+                &[staker_bump],
+            ],
+        )?;
+
+        StakerData {
+            staker: *wallet.key,
+            ..StakerData::default()
+        }
+        .serialize(&mut &mut staker_info.data.borrow_mut()[..])?;
+    }
+
+    Ok(())
+}
+
+/// Positions staked before the staker account existed have none, `None` is returned for them
+pub fn get_staker(
+    program_id: &Pubkey,
+    pool: &Pubkey,
+    wallet: &Pubkey,
+    staker_info: &AccountInfo,
+) -> Result<Option<StakerData>, ProgramError> {
+    let (staker_address, _) = get_staker_address(program_id, pool, wallet);
+
+    if *staker_info.key != staker_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if staker_info.owner != program_id {
+        return Ok(None);
+    }
+
+    match try_from_slice_unchecked::<StakerData>(&staker_info.data.borrow()) {
+        Ok(staker) => Ok(Some(staker)),
+        Err(_) => Err(ContractError::DeserializeError.into()),
+    }
+}
+
+/// Lists the position in the staker account, a full account refuses the stake until one is closed
+pub fn add_position(staker: &mut StakerData, mint: &Pubkey) -> ProgramResult {
+    if staker.mints.contains(mint) {
+        return Ok(());
+    }
+
+    if staker.mints.len() >= MAX_STAKER_POSITIONS {
+        return Err(ContractError::StakerFull.into());
+    }
+
+    staker.mints.push(*mint);
+    staker.positions = staker.mints.len() as u64;
+
+    Ok(())
+}
+
+pub fn remove_position(staker: &mut StakerData, mint: &Pubkey) {
+    staker.mints.retain(|m| m != mint);
+    staker.positions = staker.mints.len() as u64;
+}
+
+/// Saves the staker account, or hands its rent back to `payer` once its last position is gone
+/// and nothing is owed to it
+pub fn save_staker(
+    staker: &StakerData,
+    staker_info: &AccountInfo,
+    payer: &AccountInfo,
+) -> ProgramResult {
+    if staker.positions == 0 && staker.owed == 0 {
+        close_pda_account(staker_info, payer);
+    } else {
+        staker.serialize(&mut &mut staker_info.data.borrow_mut()[..])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use crate::consts::{MAX_STATS_WHITELISTS, STATS};
+use crate::error::ContractError;
+use crate::state::pda::create_pda_account;
+use crate::state::structs::{PoolStats, WhitelistCount};
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+pub fn get_stats_address(program_id: &Pubkey, pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATS, &pool.to_bytes()], program_id)
+}
+
+/// Account size of the stats with a full whitelist list
+pub fn stats_size() -> usize {
+    let count = WhitelistCount {
+        whitelist: Pubkey::default(),
+        active: 0,
+    };
+
+    PoolStats {
+        whitelists: vec![count; MAX_STATS_WHITELISTS],
+        ..PoolStats::default()
+    }
+    .try_to_vec()
+    .unwrap()
+    .len()
+}
+
+/// Creates the stats account of a pool, pools created before it existed get it on the next stake
+pub fn create_stats_account<'a>(
+    payer: &AccountInfo<'a>,
+    stats_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    program_id: &Pubkey,
+    rent: &Rent,
+    pool: &Pubkey,
+) -> ProgramResult {
+    let (stats_address, stats_bump) = get_stats_address(program_id, pool);
+
+    if *stats_info.key != stats_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if stats_info.owner != program_id {
+        create_pda_account(
+            payer,
+            stats_info,
+            system_program,
+            program_id,
+            rent,
+            stats_size(),
+            &[STATS, &pool.to_bytes(), &[stats_bump]],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn get_stats(
+    program_id: &Pubkey,
+    pool: &Pubkey,
+    stats_info: &AccountInfo,
+) -> Result<PoolStats, ProgramError> {
+    let (stats_address, _) = get_stats_address(program_id, pool);
+
+    if *stats_info.key != stats_address {
+        return Err(ContractError::InvalidInstructionData.into());
+    }
+
+    if stats_info.owner != program_id {
+        return Err(ContractError::NotInitialized.into());
+    }
+
+    match try_from_slice_unchecked::<PoolStats>(&stats_info.data.borrow()) {
+        Ok(stats) => Ok(stats),
+        Err(_) => Err(ContractError::DeserializeError.into()),
+    }
+}
+
+/// Counts the stake, a 65th whitelist entry with active stakes is refused so the breakdown stays complete
+pub fn record_stake(stats: &mut PoolStats, whitelist: &Pubkey) -> ProgramResult {
+    match stats
+        .whitelists
+        .iter()
+        .position(|c| c.whitelist == *whitelist)
+    {
+        Some(index) => stats.whitelists[index].active += 1,
+        None if stats.whitelists.len() < MAX_STATS_WHITELISTS => {
+            stats.whitelists.push(WhitelistCount {
+                whitelist: *whitelist,
+                active: 1,
+            })
+        }
+        None => return Err(ContractError::StatsFull.into()),
+    }
+
+    stats.total_active += 1;
+    stats.lifetime_stakes += 1;
+
+    Ok(())
+}
+
+/// Positions staked before the stats account existed are not counted, so counters saturate
+pub fn record_unstake(stats: &mut PoolStats, whitelist: &Pubkey) {
+    if let Some(count) = stats
+        .whitelists
+        .iter_mut()
+        .find(|c| c.whitelist == *whitelist)
+    {
+        count.active = count.active.saturating_sub(1);
+    }
+
+    stats.whitelists.retain(|c| c.active > 0);
+    stats.total_active = stats.total_active.saturating_sub(1);
+}
@@ -1,3 +1,7 @@
+use crate::consts::{
+    BASIS_POINTS, MAX_PAYOUT_PER_NFT, NFT_AMOUNT, PAYOUT_PER_DAY, REWARD_PERIOD,
+    SECONDS_IN_THE_DAY, WARMUP_PERIOD,
+};
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
@@ -9,4 +13,233 @@ pub struct StakeData {
     pub active: bool,
     pub withdrawn: u64,
     pub harvested: u64,
+    /// Reward accrued up to `checkpoint` and not paid out yet
+    pub accrued: u64,
+    pub checkpoint: u64,
+    /// Whitelist entry the NFT was staked under
+    pub whitelist: Pubkey,
+    /// Multiplier of the whitelist entry at stake time, in basis points
+    pub reward_multiplier: u64,
+    /// Rarity boost proven at stake time, in basis points
+    pub boost: u64,
+    /// Maximum payout still owed to the position, counted in the pool liability
+    pub reserved: u64,
+    /// Soft staked, the NFT stays frozen in the staker's wallet instead of the vault
+    pub frozen: bool,
+    /// End of the lock-up chosen at stake time, 0 for open-ended stakes
+    pub lock_until: u64,
+    /// Multiplier of the lock tier in basis points
+    pub lock_multiplier: u64,
+    /// Time `CompleteUnstake` can return the NFT after `RequestUnstake`, 0 when no unstake is pending
+    pub unbonding_until: u64,
+    /// Owned by the holder of the `RECEIPT` token minted at stake time rather than `staker`
+    pub receipt: bool,
+    /// Times the NFT was staked in the pool, seeds the receipt mint so every stake gets its own
+    pub stakes: u64,
+    /// `RewardLedger::raised` when the position last took its share of cap raises into `reserved`
+    pub raised_at: u64,
+}
+
+/// Per-NFT arguments of `StakeBatch`
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct StakeEntry {
+    pub eligibility: Eligibility,
+    pub boost: Option<Boost>,
+    pub lock_tier: Option<u8>,
+}
+
+/// Rarity boost of an NFT with its proof against `Pool::boost_root`
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Boost {
+    /// Boost in basis points, applied on top of the whitelist multiplier
+    pub boost: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// What a whitelist entry is keyed by
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum Eligibility {
+    /// First creator of the NFT, has to be verified
+    Creator,
+    /// Verified Metaplex collection mint
+    Collection,
+}
+
+/// Whitelisted creator or collection of a pool, stored in the `WHITELIST` PDA by `AddToWhitelist`
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct WhitelistData {
+    pub added_at: u64,
+    pub added_by: Pubkey,
+    /// New stakes are refused while disabled, open positions are not affected
+    pub enabled: bool,
+    /// Reward multiplier in basis points
+    pub reward_multiplier: u64,
+    pub eligibility: Eligibility,
+}
+
+impl Default for WhitelistData {
+    fn default() -> Self {
+        WhitelistData {
+            added_at: 0,
+            added_by: Pubkey::default(),
+            enabled: true,
+            reward_multiplier: BASIS_POINTS,
+            eligibility: Eligibility::Creator,
+        }
+    }
+}
+
+/// Deployment settings, stored in the `CONFIG` PDA by `InitializePlatform`
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PlatformConfig {
+    pub admin: Pubkey,
+    /// Proposed admin, takes over only after signing `AcceptAdmin`
+    pub pending_admin: Option<Pubkey>,
+    pub paused: PauseFlags,
+}
+
+/// Operations frozen by the admin with `SetPauseFlags`
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PauseFlags {
+    pub stake: bool,
+    pub claim: bool,
+    pub unstake: bool,
+}
+
+/// Independent staking pool, stored in the `POOL` PDA seeded by `pool_id`
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Pool {
+    pub pool_id: u64,
+    pub reward_mint: Pubkey,
+    pub vault_bump: u8,
+    /// Merkle root of `(mint, boost)` pairs published with `SetBoostRoot`
+    pub boost_root: Option<[u8; 32]>,
+    pub options: PoolOptions,
+    pub ledger: RewardLedger,
+    /// Lock-ups a stake can choose from, set with `SetLockTiers`
+    pub lock_tiers: Vec<LockTier>,
+    /// Schedule history, the last epoch is the active one
+    pub epochs: Vec<ScheduleEpoch>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct LockTier {
+    /// Lock-up in seconds
+    pub duration: u64,
+    /// Reward multiplier in basis points
+    pub multiplier: u64,
+}
+
+/// Positions of a wallet in a pool, stored in the `STAKER` PDA seeded by the pool and the wallet
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct StakerData {
+    pub staker: Pubkey,
+    /// Number of active positions, closing the last one closes the account
+    pub positions: u64,
+    pub rewards_claimed: u64,
+    pub mints: Vec<Pubkey>,
+    /// Reward of unstaked positions the vault couldn't pay or made during a claim pause,
+    /// paid by `ClaimAll`. The account stays open until it is paid
+    pub owed: u64,
+}
+
+/// Pool counters, stored in the `STATS` PDA seeded by the pool
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PoolStats {
+    pub total_active: u64,
+    pub lifetime_stakes: u64,
+    pub rewards_distributed: u64,
+    /// Active stakes per whitelist entry, entries without active stakes are dropped
+    pub whitelists: Vec<WhitelistCount>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct WhitelistCount {
+    pub whitelist: Pubkey,
+    pub active: u64,
+}
+
+/// Pool settings, changed by the admin with `SetPoolOptions`
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PoolOptions {
+    /// Refuse stakes whose maximum payout the deposited rewards can't cover
+    pub require_funded: bool,
+    /// Maximum number of active positions in the pool
+    pub max_staked: u64,
+    /// Freeze new stakes in the staker's wallet instead of moving them to the vault
+    pub soft_staking: bool,
+    /// Share of the reward in basis points forfeited by unstaking a locked position,
+    /// `None` refuses early exits
+    pub early_exit_penalty: Option<u64>,
+    /// Cooldown in seconds between `RequestUnstake` and `CompleteUnstake`, 0 keeps single-step `Unstake`
+    pub unbonding_period: u64,
+    /// Pay the reward earned so far to the current staker on `TransferPosition`,
+    /// otherwise it travels with the position
+    pub settle_on_transfer: bool,
+    /// Mint a transferable receipt token on stake, its holder claims and unstakes the position
+    pub mint_receipts: bool,
+}
+
+impl Default for PoolOptions {
+    fn default() -> Self {
+        PoolOptions {
+            require_funded: false,
+            max_staked: NFT_AMOUNT,
+            soft_staking: false,
+            early_exit_penalty: None,
+            unbonding_period: 0,
+            settle_on_transfer: false,
+            mint_receipts: false,
+        }
+    }
+}
+
+/// Reward token accounting of a pool
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct RewardLedger {
+    /// Sum of `DepositRewards`, manual transfers to the vault are not counted
+    pub total_deposited: u64,
+    pub total_paid: u64,
+    /// Maximum payout still owed to active stakes and unpaid rewards
+    pub liability: u64,
+    /// Sum of `WithdrawSurplus`
+    pub total_withdrawn: u64,
+    /// Sum of the multipliers of active positions in basis points
+    pub active_multiplier: u64,
+    /// Sum of the payout cap raises, a raise is added to the liability for every active
+    /// position at once and each one takes its share into `StakeData::reserved` later
+    pub raised: u64,
+}
+
+/// Reward schedule in effect from `start` until the start of the next epoch
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ScheduleEpoch {
+    pub start: u64,
+    pub schedule: RewardSchedule,
+}
+
+/// Reward curve of a pool, updatable by the admin with `UpdateRewardSchedule`
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct RewardSchedule {
+    /// Accrual interval in seconds
+    pub interval: u64,
+    /// Payout growth per interval, the n-th paid interval earns `n * payout_per_interval`
+    pub payout_per_interval: u64,
+    /// Intervals a stake has to complete before it starts earning
+    pub warmup_intervals: u64,
+    /// Intervals after which a stake is paid out `max_payout_per_nft`
+    pub period_intervals: u64,
+    pub max_payout_per_nft: u64,
+}
+
+impl Default for RewardSchedule {
+    fn default() -> Self {
+        RewardSchedule {
+            interval: SECONDS_IN_THE_DAY,
+            payout_per_interval: PAYOUT_PER_DAY,
+            warmup_intervals: WARMUP_PERIOD,
+            period_intervals: REWARD_PERIOD,
+            max_payout_per_nft: MAX_PAYOUT_PER_NFT,
+        }
+    }
 }
@@ -0,0 +1,72 @@
+use crate::consts::VAULT;
+use crate::processor::staking::claim::Accounts;
+use crate::state::soft_stake::thaw_nft;
+use crate::state::structs::StakeData;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::{invoke, invoke_signed};
+
+/// Gives the NFT of a position back to the staker, thawing soft stakes and emptying the vault ATA otherwise
+pub fn return_nft(accounts: &Accounts, stake_data: &StakeData, vault_bump: u8) -> ProgramResult {
+    if stake_data.frozen {
+        return thaw_nft(accounts, vault_bump);
+    }
+
+    let owner = accounts.owner();
+
+    if accounts.payer_nft_holder_info.owner != accounts.token_info.key {
+        invoke(
+            &spl_associated_token_account::create_associated_token_account(
+                accounts.payer.key,
+                owner.key,
+                accounts.nft_info.key,
+            ),
+            &[
+                accounts.payer.clone(),
+                accounts.payer_nft_holder_info.clone(),
+                owner.clone(),
+                accounts.nft_info.clone(),
+                accounts.system_program.clone(),
+                accounts.token_info.clone(),
+                accounts.rent_info.clone(),
+                accounts.assoc_acccount_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            accounts.token_info.key,
+            accounts.vault_nft_holder_info.key,
+            accounts.payer_nft_holder_info.key,
+            accounts.vault_info.key,
+            &[],
+            1,
+        )?,
+        &[
+            accounts.vault_nft_holder_info.clone(),
+            accounts.payer_nft_holder_info.clone(),
+            accounts.vault_info.clone(),
+            accounts.token_info.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            accounts.token_info.key,
+            accounts.vault_nft_holder_info.key,
+            owner.key,
+            accounts.vault_info.key,
+            &[],
+        )?,
+        &[
+            accounts.vault_nft_holder_info.clone(),
+            owner.clone(),
+            accounts.vault_info.clone(),
+            accounts.token_info.clone(),
+        ],
+        &[&[VAULT, &accounts.pool_info.key.to_bytes(), &[vault_bump]]],
+    )?;
+
+    Ok(())
+}
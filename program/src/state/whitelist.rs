@@ -0,0 +1,32 @@
+use crate::consts::WHITELIST;
+use crate::error::ContractError;
+use crate::state::structs::WhitelistData;
+use solana_program::account_info::AccountInfo;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn get_whitelist_address(program_id: &Pubkey, pool: &Pubkey, key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WHITELIST, &pool.to_bytes(), &key.to_bytes()], program_id)
+}
+
+/// Whitelist entry that accepts new stakes
+pub fn get_enabled_whitelist(
+    program_id: &Pubkey,
+    whitelist_info: &AccountInfo,
+) -> Result<WhitelistData, ProgramError> {
+    if whitelist_info.owner != program_id {
+        return Err(ContractError::WhitelistError.into());
+    }
+
+    let whitelist = match try_from_slice_unchecked::<WhitelistData>(&whitelist_info.data.borrow()) {
+        Ok(data) => data,
+        Err(_) => return Err(ContractError::DeserializeError.into()),
+    };
+
+    if !whitelist.enabled {
+        return Err(ContractError::WhitelistDisabled.into());
+    }
+
+    Ok(whitelist)
+}
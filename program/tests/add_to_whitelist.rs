@@ -2,11 +2,14 @@
 mod common;
 
 use crate::common::Env;
-use pixel_platform::id;
-use pixel_platform::instruction::PlatformInstruction;
+use solana_program::borsh::try_from_slice_unchecked;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Signer;
 use solana_sdk::transaction::Transaction;
+use staking_platform::consts::BASIS_POINTS;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::{Eligibility, WhitelistData};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_add_to_whitelist() {
@@ -14,8 +17,14 @@ async fn test_add_to_whitelist() {
 
     let program_id = id();
 
-    let instruction =
-        PlatformInstruction::add_to_whitelist(env.admin.pubkey(), env.creator, program_id);
+    let instruction = PlatformInstruction::add_to_whitelist(
+        env.admin.pubkey(),
+        env.creator,
+        program_id,
+        env.pool_id,
+        Eligibility::Creator,
+        BASIS_POINTS,
+    );
 
     let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
 
@@ -25,15 +34,28 @@ async fn test_add_to_whitelist() {
         .send_transaction(&tx)
         .expect("Transaction failed.");
 
-    let (wl_address, _) = Pubkey::find_program_address(
-        &["whitelist".as_bytes(), &env.creator.to_bytes()],
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
         &program_id,
     );
 
-    assert_eq!(
-        wl_address,
-        "5krAK8y3HiXdZzzqgo9QvsKBv2kAHM6G6FnEw2bLd99n"
-            .parse::<Pubkey>()
-            .unwrap()
+    let (wl_address, _) = Pubkey::find_program_address(
+        &[
+            "whitelist".as_bytes(),
+            &pool.to_bytes(),
+            &env.creator.to_bytes(),
+        ],
+        &program_id,
     );
+
+    let whitelist = env.client.get_account(&wl_address).unwrap();
+
+    assert_eq!(whitelist.owner, program_id);
+
+    let whitelist = try_from_slice_unchecked::<WhitelistData>(&whitelist.data).unwrap();
+
+    assert_eq!(whitelist.added_by, env.admin.pubkey());
+    assert!(whitelist.enabled);
+    assert_eq!(whitelist.reward_multiplier, BASIS_POINTS);
+    assert_eq!(whitelist.eligibility, Eligibility::Creator);
 }
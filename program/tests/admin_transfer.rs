@@ -0,0 +1,50 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::PlatformConfig;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_admin_transfer() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let propose =
+        PlatformInstruction::propose_admin(env.admin.pubkey(), env.user.pubkey(), program_id);
+    let accept = PlatformInstruction::accept_admin(env.user.pubkey(), program_id);
+
+    let mut tx = Transaction::new_with_payer(&[propose, accept], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin, &env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let config_data = env.client.get_account_data(&config).unwrap();
+    let platform_config = try_from_slice_unchecked::<PlatformConfig>(&config_data).unwrap();
+
+    assert_eq!(platform_config.admin, env.user.pubkey());
+    assert_eq!(platform_config.pending_admin, None);
+
+    let propose =
+        PlatformInstruction::propose_admin(env.user.pubkey(), env.admin.pubkey(), program_id);
+    let accept = PlatformInstruction::accept_admin(env.admin.pubkey(), program_id);
+
+    let mut tx = Transaction::new_with_payer(&[propose, accept], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin, &env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
@@ -0,0 +1,278 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::nft_metadata;
+use borsh::BorshDeserialize;
+use solana_program::instruction::Instruction;
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+use staking_platform::consts::{MAX_STAKER_POSITIONS, PACKET_DATA_SIZE};
+use staking_platform::instruction::{BatchNft, PlatformInstruction};
+use staking_platform::state::batch::{
+    join_batch, split_batch, CLAIM_ACCOUNTS, CLAIM_GROUP, FORCE_UNSTAKE_ACCOUNTS,
+    FORCE_UNSTAKE_GROUP, STAKE_ACCOUNTS, STAKE_GROUP,
+};
+use staking_platform::state::structs::{Boost, Eligibility};
+
+fn batch_nft() -> BatchNft {
+    let nft = Pubkey::new_unique();
+
+    BatchNft {
+        nft,
+        metadata: Pubkey::new_unique(),
+        metadata_data: nft_metadata(nft),
+        eligibility: Eligibility::Creator,
+        boost: None,
+        lock_tier: None,
+        owner: Some(Pubkey::new_unique()),
+        stakes: 0,
+        frozen: false,
+        receipt: false,
+    }
+}
+
+#[tokio::test]
+async fn test_split_batch() {
+    let lists: Vec<Vec<u64>> = (0..3)
+        .map(|nft| {
+            (0..STAKE_ACCOUNTS as u64)
+                .map(|i| {
+                    if STAKE_GROUP.contains(&(i as usize)) {
+                        nft * 100 + i
+                    } else {
+                        i
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let accounts = join_batch(&lists, &STAKE_GROUP);
+    assert_eq!(accounts.len(), STAKE_ACCOUNTS + 2 * STAKE_GROUP.len());
+
+    assert_eq!(
+        split_batch(&accounts, STAKE_ACCOUNTS, &STAKE_GROUP, 3).unwrap(),
+        lists
+    );
+
+    assert!(split_batch(&accounts, STAKE_ACCOUNTS, &STAKE_GROUP, 2).is_err());
+    assert!(split_batch(
+        &accounts[..STAKE_ACCOUNTS - STAKE_GROUP.len()],
+        STAKE_ACCOUNTS,
+        &STAKE_GROUP,
+        0
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_pack_batch() {
+    let wallet = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+    let nfts: Vec<BatchNft> = (0..20).map(|_| batch_nft()).collect();
+
+    let instructions = PlatformInstruction::stake_batch(wallet, program_id, 0, &nfts).unwrap();
+    assert!(instructions.len() > 1);
+
+    let mut staked = 0;
+
+    for instruction in instructions {
+        let message = Message::new(std::slice::from_ref(&instruction), Some(&wallet));
+        assert!(1 + 64 + message.serialize().len() <= PACKET_DATA_SIZE);
+
+        let entries = match PlatformInstruction::try_from_slice(&instruction.data).unwrap() {
+            PlatformInstruction::StakeBatch { entries, .. } => entries,
+            _ => panic!("not a stake batch"),
+        };
+
+        split_batch(
+            &instruction.accounts,
+            STAKE_ACCOUNTS,
+            &STAKE_GROUP,
+            entries.len(),
+        )
+        .unwrap();
+
+        staked += entries.len();
+    }
+
+    assert_eq!(staked, nfts.len());
+
+    let instructions =
+        PlatformInstruction::claim_batch(wallet, program_id, 0, Pubkey::new_unique(), &nfts)
+            .unwrap();
+
+    let claimed: usize = instructions
+        .iter()
+        .map(
+            |instruction| match PlatformInstruction::try_from_slice(&instruction.data).unwrap() {
+                PlatformInstruction::ClaimBatch { count, .. } => {
+                    split_batch(
+                        &instruction.accounts,
+                        CLAIM_ACCOUNTS,
+                        &CLAIM_GROUP,
+                        count as usize,
+                    )
+                    .unwrap();
+                    count as usize
+                }
+                _ => panic!("not a claim batch"),
+            },
+        )
+        .sum();
+
+    assert_eq!(claimed, nfts.len());
+
+    let instructions = PlatformInstruction::force_unstake(
+        wallet,
+        program_id,
+        0,
+        Pubkey::new_unique(),
+        true,
+        &nfts,
+    )
+    .unwrap();
+
+    let returned: usize = instructions
+        .iter()
+        .map(
+            |instruction| match PlatformInstruction::try_from_slice(&instruction.data).unwrap() {
+                PlatformInstruction::ForceUnstake { count, .. } => {
+                    let lists = split_batch(
+                        &instruction.accounts,
+                        FORCE_UNSTAKE_ACCOUNTS,
+                        &FORCE_UNSTAKE_GROUP,
+                        count as usize,
+                    )
+                    .unwrap();
+
+                    // every NFT goes back to its own owner, the admin signs for all of them
+                    for list in lists {
+                        assert_eq!(list[0].pubkey, wallet);
+                        assert_ne!(list[FORCE_UNSTAKE_ACCOUNTS - 1].pubkey, wallet);
+                    }
+
+                    count as usize
+                }
+                _ => panic!("not a forced unstake"),
+            },
+        )
+        .sum();
+
+    assert_eq!(returned, nfts.len());
+}
+
+#[tokio::test]
+async fn test_pack_claim_all() {
+    let wallet = Pubkey::new_unique();
+    let nfts: Vec<Pubkey> = (0..MAX_STAKER_POSITIONS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+
+    let instructions = PlatformInstruction::claim_all(
+        wallet,
+        Pubkey::new_unique(),
+        0,
+        Pubkey::new_unique(),
+        &nfts,
+    )
+    .unwrap();
+    assert!(instructions.len() > 1);
+
+    let mut claimed = 0;
+
+    for instruction in instructions {
+        let message = Message::new(std::slice::from_ref(&instruction), Some(&wallet));
+        assert!(1 + 64 + message.serialize().len() <= PACKET_DATA_SIZE);
+
+        // the shared accounts come first, one `StakeData` per position after them
+        claimed += instruction.accounts.len() - 13;
+    }
+
+    assert_eq!(claimed, nfts.len());
+}
+
+#[tokio::test]
+async fn test_pack_force_unstake() {
+    let wallet = Pubkey::new_unique();
+    let nfts: Vec<BatchNft> = (0..20).map(|_| batch_nft()).collect();
+
+    for settle in [false, true] {
+        let instructions = PlatformInstruction::force_unstake(
+            wallet,
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            settle,
+            &nfts,
+        )
+        .unwrap();
+
+        let counts: Vec<usize> = instructions
+            .iter()
+            .map(|instruction| {
+                let message = Message::new(std::slice::from_ref(instruction), Some(&wallet));
+                assert!(1 + 64 + message.serialize().len() <= PACKET_DATA_SIZE);
+
+                match PlatformInstruction::try_from_slice(&instruction.data).unwrap() {
+                    PlatformInstruction::ForceUnstake { count, .. } => count as usize,
+                    _ => panic!("not a forced unstake"),
+                }
+            })
+            .collect();
+
+        assert!(counts[0] > 1);
+        assert_eq!(counts.iter().sum::<usize>(), nfts.len());
+    }
+}
+
+fn batch_counts(instructions: &[Instruction]) -> Vec<usize> {
+    instructions
+        .iter()
+        .map(
+            |instruction| match PlatformInstruction::try_from_slice(&instruction.data).unwrap() {
+                PlatformInstruction::StakeBatch { entries, .. } => entries.len(),
+                PlatformInstruction::ClaimBatch { count, .. } => count as usize,
+                _ => panic!("not a batch"),
+            },
+        )
+        .collect()
+}
+
+#[tokio::test]
+async fn test_pack_batch_limits() {
+    let wallet = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let nfts: Vec<BatchNft> = (0..20).map(|_| batch_nft()).collect();
+
+    // without soft stakes and receipts the edition and receipt accounts are left out
+    let instructions = PlatformInstruction::stake_batch(wallet, program_id, 0, &nfts).unwrap();
+    assert!(batch_counts(&instructions)[0] > 2);
+
+    let instructions =
+        PlatformInstruction::claim_batch(wallet, program_id, 0, reward_mint, &nfts).unwrap();
+    assert!(batch_counts(&instructions)[0] > 2);
+
+    let receipts: Vec<BatchNft> = nfts
+        .iter()
+        .map(|nft| BatchNft {
+            frozen: true,
+            receipt: true,
+            ..nft.clone()
+        })
+        .collect();
+
+    let instructions =
+        PlatformInstruction::claim_batch(wallet, program_id, 0, reward_mint, &receipts).unwrap();
+    assert!(batch_counts(&instructions)[0] > 1);
+
+    // an NFT too large for a transaction of its own fails the batch instead of a transaction
+    let mut nfts = nfts;
+    nfts[1].boost = Some(Boost {
+        boost: 0,
+        proof: vec![[0; 32]; 40],
+    });
+
+    assert!(PlatformInstruction::stake_batch(wallet, program_id, 0, &nfts).is_err());
+}
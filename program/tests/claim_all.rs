@@ -0,0 +1,48 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_claim_all() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    // positions of another wallet can't be claimed
+    let instruction = PlatformInstruction::claim_all(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        &[env.nft],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&instruction, Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::claim_all(
+        env.user.pubkey(),
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        &[env.nft],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&instruction, Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
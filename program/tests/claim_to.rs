@@ -0,0 +1,67 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::Eligibility;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_claim_to() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (vault, _) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    // the vault's NFT account isn't a token account of the reward mint
+    let instruction = PlatformInstruction::claim_to(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        get_associated_token_address(&vault, &env.nft),
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::claim_to(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        get_associated_token_address(&env.admin.pubkey(), &env.reward_mint),
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
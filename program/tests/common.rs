@@ -5,6 +5,13 @@ use solana_program::pubkey::Pubkey;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::signature::Keypair;
 
+use borsh::BorshSerialize;
+use solana_program::borsh::try_from_slice_unchecked;
+use spl_token_metadata::state::{Creator, Data, Key, Metadata};
+use staking_platform::consts::BASIS_POINTS;
+use staking_platform::id;
+use staking_platform::state::structs::{Pool, PoolOptions, RewardLedger, StakeData};
+
 #[allow(dead_code)]
 pub struct Env {
     pub client: RpcClient,
@@ -15,6 +22,7 @@ pub struct Env {
     pub metadata: Pubkey,
     pub metadata_data: Vec<u8>,
     pub reward_mint: Pubkey,
+    pub pool_id: u64,
     pub recent_blockhash: Hash,
 }
 
@@ -76,7 +84,90 @@ impl Env {
             metadata,
             metadata_data,
             reward_mint,
+            pool_id: 0,
             recent_blockhash,
         }
     }
+
+    /// `StakeData::stakes` of `nft` in the test pool, 0 before its first stake
+    #[allow(dead_code)]
+    pub fn stakes(&self, nft: &Pubkey) -> u64 {
+        let (pool, _) =
+            Pubkey::find_program_address(&["pool".as_bytes(), &self.pool_id.to_le_bytes()], &id());
+
+        let (stake_data, _) =
+            Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &id());
+
+        match self.client.get_account_data(&stake_data) {
+            Ok(data) => try_from_slice_unchecked::<StakeData>(&data).unwrap().stakes,
+            Err(_) => 0,
+        }
+    }
+}
+
+/// Serialized metadata of `nft` with a single verified creator
+#[allow(dead_code)]
+pub fn nft_metadata(nft: Pubkey) -> Vec<u8> {
+    let metadata = Metadata {
+        key: Key::MetadataV1,
+        update_authority: Pubkey::new_unique(),
+        mint: nft,
+        data: Data {
+            name: "Staking NFT".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "https://example.com/nft.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![Creator {
+                address: Pubkey::new_unique(),
+                verified: true,
+                share: 0,
+            }]),
+        },
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: Some(255),
+    };
+
+    metadata.try_to_vec().unwrap()
+}
+
+/// Pool without a schedule, lock-up tiers or options
+#[allow(dead_code)]
+pub fn pool() -> Pool {
+    Pool {
+        pool_id: 0,
+        reward_mint: Pubkey::new_unique(),
+        vault_bump: 255,
+        boost_root: None,
+        options: PoolOptions::default(),
+        ledger: RewardLedger::default(),
+        lock_tiers: vec![],
+        epochs: vec![],
+    }
+}
+
+/// Active unlocked position staked at 0 with neutral multipliers
+#[allow(dead_code)]
+pub fn stake_data() -> StakeData {
+    StakeData {
+        timestamp: 0,
+        staker: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        active: true,
+        withdrawn: 0,
+        harvested: 0,
+        accrued: 0,
+        checkpoint: 0,
+        whitelist: Pubkey::new_unique(),
+        reward_multiplier: BASIS_POINTS,
+        boost: BASIS_POINTS,
+        reserved: 0,
+        frozen: false,
+        lock_until: 0,
+        lock_multiplier: BASIS_POINTS,
+        unbonding_until: 0,
+        receipt: false,
+        stakes: 1,
+        raised_at: 0,
+    }
 }
@@ -0,0 +1,36 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::Eligibility;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_complete_unstake() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::complete_unstake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
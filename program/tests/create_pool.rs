@@ -0,0 +1,48 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::pool::current_schedule;
+use staking_platform::state::structs::{Pool, RewardSchedule};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_create_pool() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::create_pool(
+        env.admin.pubkey(),
+        env.reward_mint,
+        program_id,
+        env.pool_id,
+        RewardSchedule::default(),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let pool = try_from_slice_unchecked::<Pool>(&pool_data).unwrap();
+
+    assert_eq!(pool.pool_id, env.pool_id);
+    assert_eq!(pool.reward_mint, env.reward_mint);
+    assert_eq!(pool.epochs.len(), 1);
+    assert_eq!(*current_schedule(&pool), RewardSchedule::default());
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use spl_token_metadata::state::{Creator, Data, Key, Metadata};
+use staking_platform::state::metadata::{get_verified_collection, get_whitelist_key};
+use staking_platform::state::structs::Eligibility;
+
+fn metadata_data(creator_verified: bool, collection: Option<(bool, Pubkey)>) -> (Pubkey, Vec<u8>) {
+    let creator = Pubkey::new_unique();
+
+    let metadata = Metadata {
+        key: Key::MetadataV1,
+        update_authority: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        data: Data {
+            name: "Staking NFT".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "https://example.com/nft.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![Creator {
+                address: creator,
+                verified: creator_verified,
+                share: 0,
+            }]),
+        },
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: Some(255),
+    };
+
+    let mut data = metadata.try_to_vec().unwrap();
+
+    // token standard
+    data.extend_from_slice(&[1, 0]);
+
+    match collection {
+        Some((verified, key)) => {
+            data.push(1);
+            data.push(verified as u8);
+            data.extend_from_slice(&key.to_bytes());
+        }
+        None => data.push(0),
+    }
+
+    // account padding
+    data.resize(679, 0);
+
+    (creator, data)
+}
+
+#[tokio::test]
+async fn test_creator_eligibility() {
+    let (creator, data) = metadata_data(true, None);
+    assert_eq!(
+        get_whitelist_key(&data, Eligibility::Creator).unwrap(),
+        creator
+    );
+
+    let (_, data) = metadata_data(false, None);
+    assert!(get_whitelist_key(&data, Eligibility::Creator).is_err());
+}
+
+#[tokio::test]
+async fn test_collection_eligibility() {
+    let collection = Pubkey::new_unique();
+
+    let (_, data) = metadata_data(false, Some((true, collection)));
+    assert_eq!(get_verified_collection(&data), Some(collection));
+    assert_eq!(
+        get_whitelist_key(&data, Eligibility::Collection).unwrap(),
+        collection
+    );
+
+    let (_, data) = metadata_data(true, Some((false, collection)));
+    assert_eq!(get_verified_collection(&data), None);
+    assert!(get_whitelist_key(&data, Eligibility::Collection).is_err());
+
+    let (_, data) = metadata_data(true, None);
+    assert!(get_whitelist_key(&data, Eligibility::Collection).is_err());
+
+    let (_, mut data) = metadata_data(true, None);
+    data.truncate(data.len() - 300);
+    assert_eq!(get_verified_collection(&data), None);
+}
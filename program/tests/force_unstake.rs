@@ -0,0 +1,80 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::{BatchNft, PlatformInstruction};
+use staking_platform::state::structs::{Eligibility, StakeData};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_force_unstake() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let nfts = [BatchNft {
+        nft: env.nft,
+        metadata: env.metadata,
+        metadata_data: env.metadata_data.clone(),
+        eligibility: Eligibility::Creator,
+        boost: None,
+        lock_tier: None,
+        owner: Some(env.user.pubkey()),
+        stakes: env.stakes(&env.nft),
+        frozen: false,
+        receipt: false,
+    }];
+
+    // only the admin can force an unstake
+    let instructions = PlatformInstruction::force_unstake(
+        env.user.pubkey(),
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        true,
+        &nfts,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instructions = PlatformInstruction::force_unstake(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        true,
+        &nfts,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (stake_data, _) =
+        Pubkey::find_program_address(&[&pool.to_bytes(), &env.nft.to_bytes()], &program_id);
+
+    let stake_data = env.client.get_account_data(&stake_data).unwrap();
+    let stake_data = try_from_slice_unchecked::<StakeData>(&stake_data).unwrap();
+
+    assert!(!stake_data.active);
+    assert_eq!(stake_data.accrued, 0);
+}
@@ -2,11 +2,11 @@
 mod common;
 
 use crate::common::Env;
-use pixel_platform::id;
-use pixel_platform::instruction::PlatformInstruction;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Signer;
 use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_generate_vault() {
@@ -14,7 +14,8 @@ async fn test_generate_vault() {
 
     let program_id = id();
 
-    let instruction = PlatformInstruction::generate_vault(env.admin.pubkey(), program_id);
+    let instruction =
+        PlatformInstruction::generate_vault(env.admin.pubkey(), program_id, env.pool_id);
 
     let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
 
@@ -24,12 +25,15 @@ async fn test_generate_vault() {
         .send_transaction(&tx)
         .expect("Transaction failed.");
 
-    let (vault_pda, _) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
-
-    assert_eq!(
-        vault_pda,
-        "CPfTr8fTwvPNbqm4ET7xVCNXAv8fcMeMhkA1BzwaH3m3"
-            .parse::<Pubkey>()
-            .unwrap()
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
     );
+
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let vault = env.client.get_account(&vault_pda).unwrap();
+
+    assert_eq!(vault.owner, program_id);
 }
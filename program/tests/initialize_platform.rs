@@ -0,0 +1,37 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::{PauseFlags, PlatformConfig};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_initialize_platform() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::initialize_platform(env.admin.pubkey(), program_id);
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let config_data = env.client.get_account_data(&config).unwrap();
+    let config = try_from_slice_unchecked::<PlatformConfig>(&config_data).unwrap();
+
+    assert_eq!(config.admin, env.admin.pubkey());
+    assert_eq!(config.pending_admin, None);
+    assert_eq!(config.paused, PauseFlags::default());
+}
@@ -0,0 +1,171 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::{pool, stake_data};
+use staking_platform::error::ContractError;
+use staking_platform::state::ledger::{
+    funded, payable, record_debt_payout, record_payout, release, reserve, surplus,
+};
+use staking_platform::state::pool::raise_cap;
+use staking_platform::state::structs::{RewardLedger, RewardSchedule, ScheduleEpoch, StakerData};
+
+#[tokio::test]
+async fn test_reward_ledger() {
+    let mut ledger = RewardLedger {
+        total_deposited: 1500,
+        ..RewardLedger::default()
+    };
+
+    let mut first = stake_data();
+    let mut second = stake_data();
+
+    reserve(&mut ledger, &mut first, 1000);
+    reserve(&mut ledger, &mut second, 1000);
+    assert_eq!(ledger.liability, 2000);
+    assert!(ledger.liability > funded(&ledger));
+
+    record_payout(&mut ledger, &mut first, 300);
+    assert_eq!(first.reserved, 700);
+    assert_eq!(ledger.liability, 1700);
+    assert_eq!(ledger.total_paid, 300);
+    assert_eq!(funded(&ledger), 1200);
+
+    // unstaked during a claim pause, the unpaid reward stays owed to the staker
+    let mut staker = StakerData {
+        owed: 200,
+        ..StakerData::default()
+    };
+    release(&mut ledger, &mut second, staker.owed);
+    assert_eq!(second.reserved, 0);
+    assert_eq!(ledger.liability, 900);
+
+    record_debt_payout(&mut ledger, &mut staker, 200);
+    assert_eq!(staker.owed, 0);
+    assert_eq!(ledger.liability, 700);
+
+    // restaking replaces what the previous position reserved
+    reserve(&mut ledger, &mut first, 500);
+    assert_eq!(ledger.liability, 500);
+
+    // payouts beyond the reserve never push the liability below zero
+    record_payout(&mut ledger, &mut first, 600);
+    assert_eq!(first.reserved, 0);
+    assert_eq!(ledger.liability, 0);
+    assert_eq!(ledger.total_paid, 1100);
+}
+
+#[tokio::test]
+async fn test_surplus() {
+    let mut ledger = RewardLedger {
+        total_deposited: 1000,
+        ..RewardLedger::default()
+    };
+
+    let mut position = stake_data();
+
+    reserve(&mut ledger, &mut position, 600);
+    assert_eq!(surplus(&ledger, 1000), 400);
+
+    // manual transfers to the vault count towards the surplus
+    assert_eq!(surplus(&ledger, 1500), 900);
+
+    ledger.total_withdrawn += 400;
+    assert_eq!(funded(&ledger), 600);
+    assert_eq!(surplus(&ledger, 600), 0);
+
+    release(&mut ledger, &mut position, 0);
+    assert_eq!(surplus(&ledger, 600), 600);
+}
+
+#[tokio::test]
+async fn test_shortfall() {
+    let mut ledger = RewardLedger {
+        total_deposited: 500,
+        ..RewardLedger::default()
+    };
+
+    let mut position = stake_data();
+
+    reserve(&mut ledger, &mut position, 1000);
+
+    // unstaked with 800 earned and 500 in the vault
+    let paid = payable(800, 500);
+    assert_eq!(paid, 500);
+
+    record_payout(&mut ledger, &mut position, paid);
+    let mut staker = StakerData {
+        owed: 800 - paid,
+        ..StakerData::default()
+    };
+    release(&mut ledger, &mut position, staker.owed);
+    assert_eq!(position.reserved, 0);
+    assert_eq!(ledger.liability, 300);
+    assert_eq!(surplus(&ledger, 0), 0);
+
+    // the debt is paid in full once the vault is topped up
+    ledger.total_deposited += 300;
+    assert_eq!(payable(staker.owed, 300), 300);
+
+    record_debt_payout(&mut ledger, &mut staker, 300);
+    assert_eq!(staker.owed, 0);
+    assert_eq!(ledger.liability, 0);
+    assert_eq!(ledger.total_paid, 800);
+    assert_eq!(funded(&ledger), 0);
+}
+
+#[tokio::test]
+async fn test_cap_change() {
+    let schedule = RewardSchedule::default();
+    let cap = schedule.max_payout_per_nft;
+
+    let mut pool = pool();
+    pool.epochs.push(ScheduleEpoch { start: 0, schedule });
+
+    let raised = RewardSchedule {
+        max_payout_per_nft: cap * 2,
+        ..schedule
+    };
+    let lowered = RewardSchedule {
+        max_payout_per_nft: cap / 2,
+        ..schedule
+    };
+
+    // nothing to reserve without open positions
+    assert!(raise_cap(&mut pool.clone(), &raised).is_ok());
+
+    let mut position = stake_data();
+    reserve(&mut pool.ledger, &mut position, cap);
+
+    // the raise is reserved for the open position, the funded surplus has to cover it
+    pool.ledger.total_deposited = cap * 3 / 2;
+    assert_eq!(
+        raise_cap(&mut pool.clone(), &raised),
+        Err(ContractError::InsufficientRewards.into())
+    );
+    assert!(raise_cap(&mut pool.clone(), &lowered).is_ok());
+    assert!(raise_cap(&mut pool.clone(), &schedule).is_ok());
+
+    pool.ledger.total_deposited = cap * 2;
+    raise_cap(&mut pool, &raised).unwrap();
+    pool.epochs.push(ScheduleEpoch {
+        start: 1,
+        schedule: raised,
+    });
+    assert_eq!(pool.ledger.liability, cap * 2);
+    assert_eq!(position.reserved, cap);
+
+    // the position takes its share of the raise once it is paid or closed
+    record_payout(&mut pool.ledger, &mut position, 100);
+    assert_eq!(position.reserved, cap * 2 - 100);
+    assert_eq!(pool.ledger.liability, cap * 2 - 100);
+
+    release(&mut pool.ledger, &mut position, 0);
+    assert_eq!(pool.ledger.liability, 0);
+    assert_eq!(pool.ledger.active_multiplier, 0);
+
+    // a later stake reserves the raised cap itself
+    let mut position = stake_data();
+    reserve(&mut pool.ledger, &mut position, cap * 2);
+    release(&mut pool.ledger, &mut position, 0);
+    assert_eq!(pool.ledger.liability, 0);
+}
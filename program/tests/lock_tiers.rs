@@ -0,0 +1,102 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::{pool, stake_data};
+use staking_platform::consts::{BASIS_POINTS, MAX_LOCK_TIERS, SECONDS_IN_THE_DAY};
+use staking_platform::error::ContractError;
+use staking_platform::state::pool::{
+    check_lock_tiers, check_unlocked, lock_terms, unlocked_reward,
+};
+use staking_platform::state::reward_calculation::stake_multiplier;
+use staking_platform::state::structs::{LockTier, Pool, PoolOptions, StakeData};
+
+fn locked(lock_until: u64, lock_multiplier: u64) -> StakeData {
+    StakeData {
+        boost: BASIS_POINTS * 2,
+        lock_until,
+        lock_multiplier,
+        ..stake_data()
+    }
+}
+
+#[tokio::test]
+async fn test_lock_terms() {
+    let month = LockTier {
+        duration: 30 * SECONDS_IN_THE_DAY,
+        multiplier: BASIS_POINTS + BASIS_POINTS / 2,
+    };
+    let pool = Pool {
+        lock_tiers: vec![month],
+        ..pool()
+    };
+
+    let now = 1000;
+
+    assert_eq!(lock_terms(&pool, None, now).unwrap(), (0, BASIS_POINTS));
+    assert_eq!(
+        lock_terms(&pool, Some(0), now).unwrap(),
+        (now + month.duration, month.multiplier)
+    );
+    assert_eq!(
+        lock_terms(&pool, Some(1), now),
+        Err(ContractError::InvalidLockTier.into())
+    );
+
+    assert!(check_lock_tiers(&pool.lock_tiers).is_ok());
+    assert_eq!(
+        check_lock_tiers(&[LockTier {
+            duration: SECONDS_IN_THE_DAY,
+            multiplier: 0,
+        }]),
+        Err(ContractError::InvalidMultiplier.into())
+    );
+    assert_eq!(
+        check_lock_tiers(&[month; MAX_LOCK_TIERS + 1]),
+        Err(ContractError::InvalidInstructionData.into())
+    );
+
+    let locked = locked(now + month.duration, month.multiplier);
+    assert_eq!(stake_multiplier(&locked), BASIS_POINTS * 3);
+}
+
+#[tokio::test]
+async fn test_early_exit() {
+    let locked = locked(1000, BASIS_POINTS);
+
+    let options = PoolOptions::default();
+    assert_eq!(
+        unlocked_reward(&options, &locked, 100, 999),
+        Err(ContractError::StillLocked.into())
+    );
+    assert_eq!(unlocked_reward(&options, &locked, 100, 1000).unwrap(), 100);
+
+    let options = PoolOptions {
+        early_exit_penalty: Some(BASIS_POINTS / 4),
+        ..PoolOptions::default()
+    };
+    assert_eq!(unlocked_reward(&options, &locked, 100, 999).unwrap(), 75);
+    assert_eq!(unlocked_reward(&options, &locked, 100, 1000).unwrap(), 100);
+
+    // reward owed before the position was locked isn't penalised
+    let owed = StakeData {
+        accrued: 40,
+        ..locked
+    };
+    assert_eq!(unlocked_reward(&options, &owed, 100, 999).unwrap(), 115);
+    assert_eq!(unlocked_reward(&options, &owed, 100, 1000).unwrap(), 140);
+
+    // claiming first can't skip the penalty
+    assert_eq!(
+        check_unlocked(&locked, 999),
+        Err(ContractError::StillLocked.into())
+    );
+    assert!(check_unlocked(&locked, 1000).is_ok());
+    assert!(check_unlocked(
+        &StakeData {
+            active: false,
+            ..owed
+        },
+        999
+    )
+    .is_ok());
+}
@@ -0,0 +1,41 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use solana_program::pubkey::Pubkey;
+use staking_platform::consts::BASIS_POINTS;
+use staking_platform::state::merkle::{boost_leaf, boost_node, verify_proof};
+
+#[tokio::test]
+async fn test_boost_proof() {
+    let mints = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let boosts = [BASIS_POINTS, BASIS_POINTS * 5 / 4, BASIS_POINTS * 2];
+
+    let leaves: Vec<[u8; 32]> = mints
+        .iter()
+        .zip(boosts.iter())
+        .map(|(mint, boost)| boost_leaf(mint, *boost))
+        .collect();
+
+    // odd leaf is promoted to the next level
+    let left = boost_node(&leaves[0], &leaves[1]);
+    let root = boost_node(&left, &leaves[2]);
+
+    assert!(verify_proof(&root, leaves[0], &[leaves[1], leaves[2]]));
+    assert!(verify_proof(&root, leaves[1], &[leaves[0], leaves[2]]));
+    assert!(verify_proof(&root, leaves[2], &[left]));
+
+    assert!(!verify_proof(
+        &root,
+        boost_leaf(&mints[0], BASIS_POINTS * 2),
+        &[leaves[1], leaves[2]]
+    ));
+    assert!(!verify_proof(
+        &root,
+        boost_leaf(&Pubkey::new_unique(), boosts[2]),
+        &[left]
+    ));
+}
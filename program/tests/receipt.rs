@@ -0,0 +1,271 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account as TokenAccount;
+use staking_platform::id;
+use staking_platform::instruction::{BatchNft, PlatformInstruction};
+use staking_platform::state::receipt::get_receipt_address;
+use staking_platform::state::structs::{Eligibility, PoolOptions, StakeData};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_receipt() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::set_pool_options(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        PoolOptions {
+            mint_receipts: true,
+            ..PoolOptions::default()
+        },
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let instruction = PlatformInstruction::stake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        None,
+        None,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (stake_data, _) =
+        Pubkey::find_program_address(&[&pool.to_bytes(), &env.nft.to_bytes()], &program_id);
+
+    let stake_data_data = env.client.get_account_data(&stake_data).unwrap();
+    let stake_struct = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+
+    assert!(stake_struct.receipt);
+
+    let (receipt, _) = get_receipt_address(&program_id, &pool, &env.nft, stake_struct.stakes);
+    let balance = env
+        .client
+        .get_token_account_balance(&get_associated_token_address(&env.user.pubkey(), &receipt))
+        .unwrap();
+
+    assert_eq!(balance.amount, "1");
+
+    // the admin doesn't hold the receipt
+    let instruction = PlatformInstruction::claim(
+        env.admin.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::unstake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let stake_data_data = env.client.get_account_data(&stake_data).unwrap();
+    let stake_struct = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+
+    assert!(!stake_struct.receipt);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_force_unstake_receipt() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::set_pool_options(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        PoolOptions {
+            mint_receipts: true,
+            ..PoolOptions::default()
+        },
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let stake = |stakes: u64| {
+        let instruction = PlatformInstruction::stake(
+            env.user.pubkey(),
+            env.nft,
+            program_id,
+            env.pool_id,
+            env.metadata,
+            env.metadata_data.clone(),
+            Eligibility::Creator,
+            None,
+            None,
+            stakes,
+        );
+
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+        tx.sign(&vec![&env.user], env.recent_blockhash);
+
+        env.client
+            .send_transaction(&tx)
+            .expect("Transaction failed.");
+    };
+
+    stake(env.stakes(&env.nft));
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (stake_data, _) =
+        Pubkey::find_program_address(&[&pool.to_bytes(), &env.nft.to_bytes()], &program_id);
+
+    let stakes = env.stakes(&env.nft);
+    let (old_receipt, _) = get_receipt_address(&program_id, &pool, &env.nft, stakes);
+
+    // the user holds the receipt and gets the NFT back
+    let instructions = PlatformInstruction::force_unstake(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        false,
+        &[BatchNft {
+            nft: env.nft,
+            metadata: env.metadata,
+            metadata_data: env.metadata_data.clone(),
+            eligibility: Eligibility::Creator,
+            boost: None,
+            lock_tier: None,
+            owner: Some(env.user.pubkey()),
+            stakes,
+            frozen: false,
+            receipt: true,
+        }],
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let stake_data_data = env.client.get_account_data(&stake_data).unwrap();
+    let stake_struct = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+
+    assert!(!stake_struct.active);
+    assert!(!stake_struct.receipt);
+    assert_eq!(stake_struct.staker, env.user.pubkey());
+
+    // the holder can't pass the old receipt on
+    let holder_data = env
+        .client
+        .get_account_data(&get_associated_token_address(
+            &env.user.pubkey(),
+            &old_receipt,
+        ))
+        .unwrap();
+    assert!(TokenAccount::unpack(&holder_data).unwrap().is_frozen());
+
+    stake(stakes);
+
+    // the next position has a receipt of its own, the old one controls nothing
+    let (receipt, _) = get_receipt_address(&program_id, &pool, &env.nft, stakes + 1);
+    assert_ne!(receipt, old_receipt);
+    assert_eq!(env.stakes(&env.nft), stakes + 1);
+
+    let balance = env
+        .client
+        .get_token_account_balance(&get_associated_token_address(&env.user.pubkey(), &receipt))
+        .unwrap();
+
+    assert_eq!(balance.amount, "1");
+
+    let unstake = |stakes: u64| {
+        let instruction = PlatformInstruction::unstake(
+            env.user.pubkey(),
+            env.nft,
+            program_id,
+            env.pool_id,
+            env.reward_mint,
+            env.metadata,
+            env.metadata_data.clone(),
+            Eligibility::Creator,
+            stakes,
+        );
+
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+        tx.sign(&vec![&env.user], env.recent_blockhash);
+
+        env.client.send_transaction(&tx).is_ok()
+    };
+
+    assert!(!unstake(stakes));
+    assert!(unstake(stakes + 1));
+}
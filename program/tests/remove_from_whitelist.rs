@@ -0,0 +1,85 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::consts::BASIS_POINTS;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::Eligibility;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_remove_from_whitelist() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::remove_from_whitelist(
+        env.admin.pubkey(),
+        env.creator,
+        program_id,
+        env.pool_id,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (wl_address, _) = Pubkey::find_program_address(
+        &[
+            "whitelist".as_bytes(),
+            &pool.to_bytes(),
+            &env.creator.to_bytes(),
+        ],
+        &program_id,
+    );
+
+    assert!(env.client.get_account(&wl_address).is_err());
+
+    let instruction = PlatformInstruction::stake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        None,
+        None,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::add_to_whitelist(
+        env.admin.pubkey(),
+        env.creator,
+        program_id,
+        env.pool_id,
+        Eligibility::Creator,
+        BASIS_POINTS,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
@@ -1,48 +1,89 @@
 #[cfg(feature = "test-bpf")]
 mod common;
 
-use pixel_platform::consts::{
-    MAX_PAYOUT_PER_NFT, PAYOUT_PER_DAY, REWARD_PERIOD, SECONDS_IN_THE_DAY,
-};
-use pixel_platform::state::reward_calculation::calculate_reward;
 use solana_program::msg;
+use staking_platform::consts::{
+    BASIS_POINTS, MAX_PAYOUT_PER_NFT, PAYOUT_PER_DAY, REWARD_PERIOD, SECONDS_IN_THE_DAY,
+};
+use staking_platform::state::reward_calculation::{calculate_reward, cumulative_reward};
+use staking_platform::state::structs::{RewardSchedule, ScheduleEpoch};
 
 #[tokio::test]
 async fn test_reward_calculation() {
+    let epochs = [ScheduleEpoch {
+        start: 0,
+        schedule: RewardSchedule::default(),
+    }];
+
     let now = REWARD_PERIOD * SECONDS_IN_THE_DAY * 2;
 
-    let reward = calculate_reward(now, now, 0, 0);
+    let reward = calculate_reward(&epochs, now, now, now, 0, BASIS_POINTS);
     msg!(
         "edge case - JUST staked (0 seconds in staking pool) => {:?}",
         reward
     );
     assert_eq!(reward, 0);
 
-    let reward = calculate_reward(now, now - SECONDS_IN_THE_DAY + 1, 0, 0);
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - SECONDS_IN_THE_DAY + 1,
+        now - SECONDS_IN_THE_DAY + 1,
+        0,
+        BASIS_POINTS,
+    );
     msg!("0 day => {:?}", reward);
     assert_eq!(reward, 0);
 
-    let reward = calculate_reward(now, now - SECONDS_IN_THE_DAY, 0, 0);
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - SECONDS_IN_THE_DAY,
+        now - SECONDS_IN_THE_DAY,
+        0,
+        BASIS_POINTS,
+    );
     msg!("1 day => {:?}", reward);
     assert_eq!(reward, 0);
 
-    let reward = calculate_reward(now, now - SECONDS_IN_THE_DAY - 1, 0, 0);
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - SECONDS_IN_THE_DAY - 1,
+        now - SECONDS_IN_THE_DAY - 1,
+        0,
+        BASIS_POINTS,
+    );
     msg!("1 day and 1 second => {:?}", reward);
     assert_eq!(reward, 0);
 
     let mut reward = 0;
     for i in 2..=REWARD_PERIOD {
         let previous_reward = reward;
-        reward = calculate_reward(now, now - SECONDS_IN_THE_DAY * i, 0, 0);
+        reward = calculate_reward(
+            &epochs,
+            now,
+            now - SECONDS_IN_THE_DAY * i,
+            now - SECONDS_IN_THE_DAY * i,
+            0,
+            BASIS_POINTS,
+        );
         msg!("{:?} day => {:?}", i, reward);
         assert_eq!(reward, PAYOUT_PER_DAY * (i - 1) + previous_reward);
     }
 
-    let reward = calculate_reward(now, now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1), 0, 0);
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
+        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
+        0,
+        BASIS_POINTS,
+    );
     msg!("181 day => {:?}", reward);
     assert_eq!(reward, MAX_PAYOUT_PER_NFT);
 
-    let reward = calculate_reward(now, 0, 0, 0);
+    let reward = calculate_reward(&epochs, now, 0, 0, 0, BASIS_POINTS);
     msg!(
         "edge case - MAX staking time (360 days for this test pool) => {:?}",
         reward
@@ -50,56 +91,342 @@ async fn test_reward_calculation() {
     assert_eq!(reward, MAX_PAYOUT_PER_NFT);
 
     let reward = calculate_reward(
+        &epochs,
         now,
         now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
+        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
         MAX_PAYOUT_PER_NFT,
-        0,
+        BASIS_POINTS,
     );
     msg!("MAX reward harvested => {:?}", reward);
     assert_eq!(reward, 0);
 
-    let reward = calculate_reward(
-        now,
-        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
-        MAX_PAYOUT_PER_NFT,
-        MAX_PAYOUT_PER_NFT,
-    );
-    msg!("MAX reward claimed => {:?}", reward);
-    assert_eq!(reward, 0);
+    let staked = now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1);
+    let checkpoint = staked + SECONDS_IN_THE_DAY * 90;
+    let earned = cumulative_reward(&RewardSchedule::default(), checkpoint - staked);
+    let reward = calculate_reward(&epochs, now, staked, checkpoint, earned, BASIS_POINTS);
+    msg!("checkpoint after 90 days => {:?}", reward);
+    assert_eq!(earned, PAYOUT_PER_DAY * 89 * 90 / 2);
+    assert_eq!(reward, MAX_PAYOUT_PER_NFT - earned);
 
+    let staked = now - SECONDS_IN_THE_DAY * 10;
+    let checkpoint = staked + SECONDS_IN_THE_DAY * 3;
+    let switched = [
+        epochs[0],
+        ScheduleEpoch {
+            start: staked + SECONDS_IN_THE_DAY * 6,
+            schedule: RewardSchedule {
+                payout_per_interval: PAYOUT_PER_DAY * 2,
+                ..RewardSchedule::default()
+            },
+        },
+    ];
     let reward = calculate_reward(
+        &switched,
         now,
-        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
-        MAX_PAYOUT_PER_NFT / 2,
-        MAX_PAYOUT_PER_NFT / 2,
+        staked,
+        checkpoint,
+        PAYOUT_PER_DAY * (1 + 2),
+        BASIS_POINTS,
+    );
+    msg!(
+        "checkpoint mid-epoch, then a schedule change => {:?}",
+        reward
+    );
+    assert_eq!(
+        reward,
+        PAYOUT_PER_DAY * (3 + 4 + 5) + PAYOUT_PER_DAY * 2 * (6 + 7 + 8 + 9)
     );
-    msg!("50% harvested and claimed => {:?}", reward);
-    assert_eq!(reward, MAX_PAYOUT_PER_NFT / 2);
 
     let reward = calculate_reward(
+        &epochs,
         now,
         now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
+        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
         MAX_PAYOUT_PER_NFT / 2,
-        0,
+        BASIS_POINTS,
     );
     msg!("50% harvested => {:?}", reward);
     assert_eq!(reward, MAX_PAYOUT_PER_NFT / 2);
 
     let reward = calculate_reward(
+        &epochs,
         now,
         now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
+        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
         MAX_PAYOUT_PER_NFT - 1,
-        0,
+        BASIS_POINTS,
     );
     msg!("99% harvested => {:?}", reward);
     assert_eq!(reward, 1);
 
     let reward = calculate_reward(
+        &epochs,
         now,
         now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
+        now - SECONDS_IN_THE_DAY * (REWARD_PERIOD + 1),
         MAX_PAYOUT_PER_NFT - PAYOUT_PER_DAY * 180,
-        0,
+        BASIS_POINTS,
     );
     msg!("99% harvested => {:?}", reward);
     assert_eq!(reward, PAYOUT_PER_DAY * 180);
 }
+
+#[tokio::test]
+async fn test_custom_reward_schedule() {
+    let schedule = RewardSchedule {
+        interval: 60 * 60,
+        payout_per_interval: 10,
+        warmup_intervals: 3,
+        period_intervals: 10,
+        max_payout_per_nft: 1000,
+    };
+    let epochs = [ScheduleEpoch { start: 0, schedule }];
+
+    let now = schedule.interval * schedule.period_intervals * 2;
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 3,
+        now - schedule.interval * 3,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("last warm-up interval => {:?}", reward);
+    assert_eq!(reward, 0);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 4,
+        now - schedule.interval * 4,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("first paid interval => {:?}", reward);
+    assert_eq!(reward, 10);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 6,
+        now - schedule.interval * 6,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("third paid interval => {:?}", reward);
+    assert_eq!(reward, 10 + 20 + 30);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 10,
+        now - schedule.interval * 10,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("end of the period => {:?}", reward);
+    assert_eq!(reward, 280);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 11,
+        now - schedule.interval * 11,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("after the period => {:?}", reward);
+    assert_eq!(reward, 1000);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 11,
+        now - schedule.interval * 11,
+        900,
+        BASIS_POINTS,
+    );
+    msg!("capped by harvested => {:?}", reward);
+    assert_eq!(reward, 100);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 6,
+        now,
+        60,
+        BASIS_POINTS,
+    );
+    msg!("claimed just now => {:?}", reward);
+    assert_eq!(reward, 0);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        now - schedule.interval * 6,
+        now - schedule.interval,
+        30,
+        BASIS_POINTS,
+    );
+    msg!("claimed one interval ago => {:?}", reward);
+    assert_eq!(reward, 30);
+}
+
+#[tokio::test]
+async fn test_schedule_switch_mid_stake() {
+    let interval = 60 * 60;
+    let before = RewardSchedule {
+        interval,
+        payout_per_interval: 10,
+        warmup_intervals: 0,
+        period_intervals: 100,
+        max_payout_per_nft: 1_000_000,
+    };
+    let after = RewardSchedule {
+        payout_per_interval: 20,
+        ..before
+    };
+
+    let stake_timestamp = interval * 10;
+    let switch = stake_timestamp + interval * 5;
+    let now = stake_timestamp + interval * 8;
+
+    let epochs = [
+        ScheduleEpoch {
+            start: 0,
+            schedule: before,
+        },
+        ScheduleEpoch {
+            start: switch,
+            schedule: after,
+        },
+    ];
+
+    let accrued_before = calculate_reward(
+        &epochs[..1],
+        switch,
+        stake_timestamp,
+        stake_timestamp,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("accrued before the switch => {:?}", accrued_before);
+    assert_eq!(accrued_before, 10 * (1 + 2 + 3 + 4 + 5));
+
+    let reward = calculate_reward(
+        &epochs,
+        switch,
+        stake_timestamp,
+        stake_timestamp,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("switch is not retroactive => {:?}", reward);
+    assert_eq!(reward, accrued_before);
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        stake_timestamp,
+        stake_timestamp,
+        0,
+        BASIS_POINTS,
+    );
+    msg!(
+        "new schedule prices intervals after the switch => {:?}",
+        reward
+    );
+    assert_eq!(reward, accrued_before + 20 * (6 + 7 + 8));
+
+    let reward = calculate_reward(
+        &epochs,
+        now,
+        stake_timestamp,
+        switch,
+        accrued_before,
+        BASIS_POINTS,
+    );
+    msg!("checkpoint taken at the switch => {:?}", reward);
+    assert_eq!(reward, 20 * (6 + 7 + 8));
+
+    let lowered = [
+        epochs[0],
+        ScheduleEpoch {
+            start: switch,
+            schedule: RewardSchedule {
+                max_payout_per_nft: 100,
+                ..before
+            },
+        },
+    ];
+
+    let reward = calculate_reward(
+        &lowered,
+        now,
+        stake_timestamp,
+        stake_timestamp,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("lowered cap keeps what was accrued => {:?}", reward);
+    assert_eq!(reward, accrued_before);
+
+    let stopped = [
+        epochs[0],
+        ScheduleEpoch {
+            start: switch,
+            schedule: RewardSchedule {
+                payout_per_interval: 0,
+                ..before
+            },
+        },
+        ScheduleEpoch {
+            start: switch + interval * 2,
+            schedule: before,
+        },
+    ];
+
+    let reward = calculate_reward(
+        &stopped,
+        now,
+        stake_timestamp,
+        stake_timestamp,
+        0,
+        BASIS_POINTS,
+    );
+    msg!("paused accrual for two intervals => {:?}", reward);
+    assert_eq!(reward, accrued_before + 10 * 8);
+}
+
+#[tokio::test]
+async fn test_reward_multiplier() {
+    let schedule = RewardSchedule {
+        interval: 60 * 60,
+        payout_per_interval: 10,
+        warmup_intervals: 0,
+        period_intervals: 10,
+        max_payout_per_nft: 1000,
+    };
+    let epochs = [ScheduleEpoch { start: 0, schedule }];
+
+    let now = schedule.interval * schedule.period_intervals * 2;
+    let staked = now - schedule.interval * 3;
+
+    let reward = calculate_reward(&epochs, now, staked, staked, 0, BASIS_POINTS * 3 / 2);
+    msg!("150% multiplier => {:?}", reward);
+    assert_eq!(reward, 15 * (1 + 2 + 3));
+
+    let reward = calculate_reward(&epochs, now, staked, staked, 0, BASIS_POINTS / 2);
+    msg!("50% multiplier => {:?}", reward);
+    assert_eq!(reward, 5 * (1 + 2 + 3));
+
+    let reward = calculate_reward(&epochs, now, 0, 0, 0, BASIS_POINTS * 2);
+    msg!("multiplier scales the cap => {:?}", reward);
+    assert_eq!(reward, 2000);
+
+    let reward = calculate_reward(&epochs, now, 0, 0, 1500, BASIS_POINTS * 2);
+    msg!("harvested counts towards the scaled cap => {:?}", reward);
+    assert_eq!(reward, 500);
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::consts::BASIS_POINTS;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::merkle::boost_leaf;
+use staking_platform::state::structs::Pool;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_set_boost_root() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let root = boost_leaf(&env.nft, BASIS_POINTS * 2);
+
+    let instruction = PlatformInstruction::set_boost_root(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        Some(root),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let pool_struct = try_from_slice_unchecked::<Pool>(&pool_data).unwrap();
+
+    assert_eq!(pool_struct.boost_root, Some(root));
+
+    let instruction =
+        PlatformInstruction::set_boost_root(env.user.pubkey(), program_id, env.pool_id, None);
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction =
+        PlatformInstruction::set_boost_root(env.admin.pubkey(), program_id, env.pool_id, None);
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let pool_struct = try_from_slice_unchecked::<Pool>(&pool_data).unwrap();
+
+    assert_eq!(pool_struct.boost_root, None);
+}
@@ -0,0 +1,80 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::consts::{BASIS_POINTS, SECONDS_IN_THE_DAY};
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::{LockTier, Pool};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_set_lock_tiers() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let tiers = vec![
+        LockTier {
+            duration: 30 * SECONDS_IN_THE_DAY,
+            multiplier: BASIS_POINTS + BASIS_POINTS / 10,
+        },
+        LockTier {
+            duration: 90 * SECONDS_IN_THE_DAY,
+            multiplier: BASIS_POINTS + BASIS_POINTS / 4,
+        },
+    ];
+
+    let instruction = PlatformInstruction::set_lock_tiers(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        tiers.clone(),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let pool_struct = try_from_slice_unchecked::<Pool>(&pool_data).unwrap();
+
+    assert_eq!(pool_struct.lock_tiers, tiers);
+
+    let instruction =
+        PlatformInstruction::set_lock_tiers(env.user.pubkey(), program_id, env.pool_id, vec![]);
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::set_lock_tiers(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        vec![LockTier {
+            duration: SECONDS_IN_THE_DAY,
+            multiplier: 0,
+        }],
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+}
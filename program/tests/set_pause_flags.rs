@@ -0,0 +1,80 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::{Eligibility, PauseFlags, PlatformConfig};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_set_pause_flags() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let paused = PauseFlags {
+        stake: true,
+        claim: true,
+        unstake: false,
+    };
+
+    let instruction = PlatformInstruction::set_pause_flags(env.admin.pubkey(), program_id, paused);
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let config_data = env.client.get_account_data(&config).unwrap();
+    let config_data = try_from_slice_unchecked::<PlatformConfig>(&config_data).unwrap();
+
+    assert_eq!(config_data.paused, paused);
+
+    let instruction = PlatformInstruction::stake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        None,
+        None,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction =
+        PlatformInstruction::set_pause_flags(env.user.pubkey(), program_id, PauseFlags::default());
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction =
+        PlatformInstruction::set_pause_flags(env.admin.pubkey(), program_id, PauseFlags::default());
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
@@ -0,0 +1,95 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::consts::SECONDS_IN_THE_DAY;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::{Pool, PoolOptions};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_set_pool_options() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let options = PoolOptions {
+        require_funded: true,
+        max_staked: 1,
+        soft_staking: true,
+        early_exit_penalty: Some(5000),
+        unbonding_period: SECONDS_IN_THE_DAY,
+        settle_on_transfer: true,
+        mint_receipts: false,
+    };
+
+    let instruction =
+        PlatformInstruction::set_pool_options(env.admin.pubkey(), program_id, env.pool_id, options);
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let pool_struct = try_from_slice_unchecked::<Pool>(&pool_data).unwrap();
+
+    assert_eq!(pool_struct.options, options);
+
+    let instruction = PlatformInstruction::set_pool_options(
+        env.user.pubkey(),
+        program_id,
+        env.pool_id,
+        PoolOptions::default(),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    // receipts can't follow soft staked NFTs
+    let instruction = PlatformInstruction::set_pool_options(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        PoolOptions {
+            mint_receipts: true,
+            ..options
+        },
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::set_pool_options(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        PoolOptions::default(),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::{nft_metadata, Env};
+use solana_program::instruction::Instruction;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use spl_token::state::{Account, AccountState};
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::soft_stake::{
+    delegated_account_instruction, get_edition_address, FREEZE_DELEGATED_ACCOUNT,
+    THAW_DELEGATED_ACCOUNT,
+};
+use staking_platform::state::structs::{Eligibility, PoolOptions};
+
+/// Accounts written by a CPI have to be writable in the instruction calling it
+fn assert_writable(instruction: &Instruction, cpi: &Instruction) {
+    for meta in cpi.accounts.iter().filter(|meta| meta.is_writable) {
+        assert!(
+            instruction
+                .accounts
+                .iter()
+                .any(|outer| outer.pubkey == meta.pubkey && outer.is_writable),
+            "{} is read-only",
+            meta.pubkey
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_delegate_privileges() {
+    let wallet = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+    let nft = Pubkey::new_unique();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &0u64.to_le_bytes()], &program_id);
+
+    let (vault, _) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let holder = spl_associated_token_account::get_associated_token_address(&wallet, &nft);
+
+    let stake = PlatformInstruction::stake(
+        wallet,
+        nft,
+        program_id,
+        0,
+        Pubkey::new_unique(),
+        nft_metadata(nft),
+        Eligibility::Creator,
+        None,
+        None,
+        0,
+    );
+
+    let freeze = delegated_account_instruction(
+        FREEZE_DELEGATED_ACCOUNT,
+        &vault,
+        &holder,
+        &get_edition_address(&nft),
+        &nft,
+    );
+
+    assert_writable(&stake, &freeze);
+
+    let unstake = PlatformInstruction::unstake(
+        wallet,
+        nft,
+        program_id,
+        0,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        nft_metadata(nft),
+        Eligibility::Creator,
+        0,
+    );
+
+    let thaw = delegated_account_instruction(
+        THAW_DELEGATED_ACCOUNT,
+        &vault,
+        &holder,
+        &get_edition_address(&nft),
+        &nft,
+    );
+
+    assert_writable(&unstake, &thaw);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_soft_stake() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let set_soft_staking = |soft_staking: bool| {
+        let instruction = PlatformInstruction::set_pool_options(
+            env.admin.pubkey(),
+            program_id,
+            env.pool_id,
+            PoolOptions {
+                soft_staking,
+                ..PoolOptions::default()
+            },
+        );
+
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+        tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+        env.client
+            .send_transaction(&tx)
+            .expect("Transaction failed.");
+    };
+
+    set_soft_staking(true);
+
+    let holder =
+        spl_associated_token_account::get_associated_token_address(&env.user.pubkey(), &env.nft);
+
+    let instruction = PlatformInstruction::stake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        None,
+        None,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    // the NFT stays in the wallet, frozen with the vault as delegate
+    let account = Account::unpack(&env.client.get_account_data(&holder).unwrap()).unwrap();
+    assert_eq!(account.amount, 1);
+    assert_eq!(account.state, AccountState::Frozen);
+    assert!(account.delegate.is_some());
+
+    let instruction = PlatformInstruction::unstake(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let account = Account::unpack(&env.client.get_account_data(&holder).unwrap()).unwrap();
+    assert_eq!(account.state, AccountState::Initialized);
+    assert_eq!(account.delegate, COption::None);
+
+    set_soft_staking(false);
+}
@@ -2,10 +2,11 @@
 mod common;
 
 use crate::common::Env;
-use pixel_platform::id;
-use pixel_platform::instruction::PlatformInstruction;
 use solana_sdk::signature::Signer;
 use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::Eligibility;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn test_stake() {
@@ -17,8 +18,13 @@ async fn test_stake() {
         env.user.pubkey(),
         env.nft,
         program_id,
+        env.pool_id,
         env.metadata,
-        env.metadata_data,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        None,
+        None,
+        env.stakes(&env.nft),
     );
 
     let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
@@ -0,0 +1,47 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use solana_program::pubkey::Pubkey;
+use staking_platform::consts::MAX_STAKER_POSITIONS;
+use staking_platform::error::ContractError;
+use staking_platform::state::staker::{add_position, remove_position};
+use staking_platform::state::structs::StakerData;
+
+#[tokio::test]
+async fn test_staker_positions() {
+    let mut staker = StakerData {
+        staker: Pubkey::new_unique(),
+        ..StakerData::default()
+    };
+
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+
+    add_position(&mut staker, &first).unwrap();
+    add_position(&mut staker, &second).unwrap();
+    add_position(&mut staker, &first).unwrap();
+    assert_eq!(staker.mints, vec![first, second]);
+    assert_eq!(staker.positions, 2);
+
+    remove_position(&mut staker, &first);
+    assert_eq!(staker.mints, vec![second]);
+    assert_eq!(staker.positions, 1);
+
+    remove_position(&mut staker, &second);
+    assert!(staker.mints.is_empty());
+    assert_eq!(staker.positions, 0);
+}
+
+#[tokio::test]
+async fn test_staker_full() {
+    let mut staker = StakerData::default();
+
+    for _ in 0..MAX_STAKER_POSITIONS {
+        add_position(&mut staker, &Pubkey::new_unique()).unwrap();
+    }
+
+    assert_eq!(
+        add_position(&mut staker, &Pubkey::new_unique()),
+        Err(ContractError::StakerFull.into())
+    );
+}
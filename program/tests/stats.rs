@@ -0,0 +1,54 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use solana_program::pubkey::Pubkey;
+use staking_platform::consts::MAX_STATS_WHITELISTS;
+use staking_platform::error::ContractError;
+use staking_platform::state::stats::{record_stake, record_unstake};
+use staking_platform::state::structs::PoolStats;
+
+#[tokio::test]
+async fn test_pool_stats() {
+    let mut stats = PoolStats::default();
+
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+
+    record_stake(&mut stats, &first).unwrap();
+    record_stake(&mut stats, &first).unwrap();
+    record_stake(&mut stats, &second).unwrap();
+    assert_eq!(stats.total_active, 3);
+    assert_eq!(stats.lifetime_stakes, 3);
+    assert_eq!(stats.whitelists.len(), 2);
+    assert_eq!(stats.whitelists[0].active, 2);
+
+    record_unstake(&mut stats, &second);
+    assert_eq!(stats.total_active, 2);
+    assert_eq!(stats.lifetime_stakes, 3);
+    assert_eq!(stats.whitelists.len(), 1);
+    assert_eq!(stats.whitelists[0].whitelist, first);
+
+    // positions staked before the stats account existed
+    let mut stats = PoolStats::default();
+    record_unstake(&mut stats, &first);
+    assert_eq!(stats, PoolStats::default());
+}
+
+#[tokio::test]
+async fn test_stats_full() {
+    let mut stats = PoolStats::default();
+
+    for _ in 0..MAX_STATS_WHITELISTS {
+        record_stake(&mut stats, &Pubkey::new_unique()).unwrap();
+    }
+
+    assert_eq!(
+        record_stake(&mut stats, &Pubkey::new_unique()),
+        Err(ContractError::StatsFull.into())
+    );
+
+    // entries already tracked can still grow
+    let tracked = stats.whitelists[0].whitelist;
+    record_stake(&mut stats, &tracked).unwrap();
+    assert_eq!(stats.whitelists[0].active, 2);
+}
@@ -0,0 +1,101 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::{Eligibility, StakeData};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_transfer_position() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let instruction = PlatformInstruction::transfer_position(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.admin.pubkey(),
+        false,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (stake_data, _) =
+        Pubkey::find_program_address(&[&pool.to_bytes(), &env.nft.to_bytes()], &program_id);
+
+    let stake_data_data = env.client.get_account_data(&stake_data).unwrap();
+    let stake_struct = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+
+    assert_eq!(stake_struct.staker, env.admin.pubkey());
+
+    // the previous staker can't move it any more
+    let instruction = PlatformInstruction::transfer_position(
+        env.user.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.creator,
+        false,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::transfer_position(
+        env.admin.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        env.reward_mint,
+        env.metadata,
+        env.metadata_data.clone(),
+        Eligibility::Creator,
+        env.user.pubkey(),
+        true,
+        env.stakes(&env.nft),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin, &env.user], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let stake_data_data = env.client.get_account_data(&stake_data).unwrap();
+    let stake_struct = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+
+    assert_eq!(stake_struct.staker, env.user.pubkey());
+}
@@ -0,0 +1,81 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::pool::current_schedule;
+use staking_platform::state::structs::{Pool, RewardSchedule};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_update_reward_schedule() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let schedule = RewardSchedule {
+        interval: 60 * 60,
+        payout_per_interval: 1000,
+        warmup_intervals: 0,
+        period_intervals: 24 * 30,
+        max_payout_per_nft: 260_000_000,
+    };
+
+    let instruction = PlatformInstruction::update_reward_schedule(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        schedule,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let pool = try_from_slice_unchecked::<Pool>(&pool_data).unwrap();
+
+    assert!(pool.epochs.len() > 1);
+    assert_eq!(*current_schedule(&pool), schedule);
+
+    let instruction = PlatformInstruction::update_reward_schedule(
+        env.user.pubkey(),
+        program_id,
+        env.pool_id,
+        RewardSchedule::default(),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::update_reward_schedule(
+        env.admin.pubkey(),
+        program_id,
+        env.pool_id,
+        RewardSchedule::default(),
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
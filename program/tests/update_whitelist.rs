@@ -0,0 +1,88 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::consts::BASIS_POINTS;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::WhitelistData;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_update_whitelist() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let (wl_address, _) = Pubkey::find_program_address(
+        &[
+            "whitelist".as_bytes(),
+            &pool.to_bytes(),
+            &env.creator.to_bytes(),
+        ],
+        &program_id,
+    );
+
+    let instruction = PlatformInstruction::update_whitelist(
+        env.admin.pubkey(),
+        env.creator,
+        program_id,
+        env.pool_id,
+        BASIS_POINTS * 3 / 2,
+        false,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let whitelist = env.client.get_account(&wl_address).unwrap();
+    let whitelist = try_from_slice_unchecked::<WhitelistData>(&whitelist.data).unwrap();
+
+    assert_eq!(whitelist.reward_multiplier, BASIS_POINTS * 3 / 2);
+    assert!(!whitelist.enabled);
+
+    let instruction = PlatformInstruction::update_whitelist(
+        env.user.pubkey(),
+        env.creator,
+        program_id,
+        env.pool_id,
+        BASIS_POINTS * 2,
+        true,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::update_whitelist(
+        env.admin.pubkey(),
+        env.creator,
+        program_id,
+        env.pool_id,
+        BASIS_POINTS,
+        true,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+}
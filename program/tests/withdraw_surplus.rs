@@ -0,0 +1,89 @@
+#[cfg(feature = "test-bpf")]
+mod common;
+
+use crate::common::Env;
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+use staking_platform::id;
+use staking_platform::instruction::PlatformInstruction;
+use staking_platform::state::structs::Pool;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_withdraw_surplus() {
+    let env = Env::new().await;
+
+    let program_id = id();
+
+    let (pool, _) = Pubkey::find_program_address(
+        &["pool".as_bytes(), &env.pool_id.to_le_bytes()],
+        &program_id,
+    );
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let before = try_from_slice_unchecked::<Pool>(&pool_data).unwrap().ledger;
+
+    let instruction = PlatformInstruction::withdraw_surplus(
+        env.user.pubkey(),
+        env.reward_mint,
+        program_id,
+        env.pool_id,
+        1,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.user.pubkey()));
+
+    tx.sign(&vec![&env.user], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::withdraw_surplus(
+        env.admin.pubkey(),
+        env.reward_mint,
+        program_id,
+        env.pool_id,
+        u64::MAX,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+
+    let instruction = PlatformInstruction::withdraw_surplus(
+        env.admin.pubkey(),
+        env.reward_mint,
+        program_id,
+        env.pool_id,
+        1,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    env.client
+        .send_transaction(&tx)
+        .expect("Transaction failed.");
+
+    let pool_data = env.client.get_account_data(&pool).unwrap();
+    let after = try_from_slice_unchecked::<Pool>(&pool_data).unwrap().ledger;
+
+    assert_eq!(after.total_withdrawn, before.total_withdrawn + 1);
+
+    let instruction = PlatformInstruction::withdraw_surplus(
+        env.admin.pubkey(),
+        env.nft,
+        program_id,
+        env.pool_id,
+        1,
+    );
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(&env.admin.pubkey()));
+
+    tx.sign(&vec![&env.admin], env.recent_blockhash);
+
+    assert!(env.client.send_transaction(&tx).is_err());
+}
@@ -0,0 +1,72 @@
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+
+/// Positions of the per-NFT accounts in the `Stake` account list
+pub const STAKE_GROUP: [usize; 9] = [1, 2, 4, 5, 10, 11, 16, 18, 19];
+
+/// Positions of the per-NFT accounts in the `Claim` and `Unstake` account list
+pub const CLAIM_GROUP: [usize; 9] = [2, 6, 10, 11, 12, 13, 19, 21, 22];
+
+/// Positions of the per-NFT accounts in the `ForceUnstake` account list, the owner comes last
+pub const FORCE_UNSTAKE_GROUP: [usize; 10] = [2, 6, 8, 10, 11, 18, 19, 21, 22, 23];
+
+/// Stands in for the accounts an NFT of a batch doesn't use, every list has it already
+pub fn unused_account() -> AccountMeta {
+    AccountMeta::new_readonly(system_program::id(), false)
+}
+
+/// Shared accounts once, taken from the first list, followed by the per-NFT accounts of every list
+pub fn join_batch(lists: &[Vec<AccountMeta>], group: &[usize]) -> Vec<AccountMeta> {
+    let mut accounts: Vec<AccountMeta> = lists[0]
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !group.contains(i))
+        .map(|(_, account)| account.clone())
+        .collect();
+
+    for list in lists {
+        accounts.extend(group.iter().map(|g| list[*g].clone()));
+    }
+
+    accounts
+}
+
+/// Splits `count` NFTs into ranges whose instruction, built by `build`, fits in a transaction,
+/// fails with the NFT that doesn't fit on its own
+pub fn pack_batch<F>(
+    wallet_pubkey: &Pubkey,
+    count: usize,
+    build: F,
+) -> Result<Vec<Instruction>, usize>
+where
+    F: Fn(std::ops::Range<usize>) -> Instruction,
+{
+    let fits = |instruction: Instruction| {
+        let message = Message::new(&[instruction], Some(wallet_pubkey));
+
+        1 + 64 + message.serialize().len() <= PACKET_DATA_SIZE
+    };
+
+    let mut instructions = vec![];
+    let mut start = 0;
+
+    while start < count {
+        let mut end = start + 1;
+
+        if !fits(build(start..end)) {
+            return Err(start);
+        }
+
+        while end < count && end - start < u8::MAX as usize && fits(build(start..end + 1)) {
+            end += 1;
+        }
+
+        instructions.push(build(start..end));
+        start = end;
+    }
+
+    Ok(instructions)
+}
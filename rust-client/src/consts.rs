@@ -1,4 +1,12 @@
 pub const PROGRAM_ID: &str = "";
-pub const REWARD_MINT: &str = "";
 pub const RENT: &str = "SysvarRent111111111111111111111111111111111";
 pub const ASSOCIATED_TOKEN: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+pub const PAYOUT_PER_DAY: &str = "7438286";
+pub const MAX_PAYOUT_PER_NFT: &str = "119830787460";
+pub const REWARD_PERIOD: &str = "180";
+pub const WARMUP_PERIOD: &str = "1";
+pub const SECONDS_IN_THE_DAY: &str = "86400";
+
+pub const BASIS_POINTS: &str = "10000";
+pub const NFT_AMOUNT: &str = "3500";
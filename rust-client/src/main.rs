@@ -1,12 +1,42 @@
+mod batch;
 mod consts;
+mod merkle;
+mod metadata;
+mod receipt;
+mod recipient;
 mod structs;
 mod transactions;
 
+use crate::consts::{
+    BASIS_POINTS, MAX_PAYOUT_PER_NFT, NFT_AMOUNT, PAYOUT_PER_DAY, REWARD_PERIOD,
+    SECONDS_IN_THE_DAY, WARMUP_PERIOD,
+};
+use crate::transactions::accept_admin::accept_admin;
 use crate::transactions::add_to_whitelist::add_to_whitelist;
 use crate::transactions::claim::claim;
+use crate::transactions::claim_all::claim_all;
+use crate::transactions::claim_batch::claim_batch;
+use crate::transactions::complete_unstake::complete_unstake;
+use crate::transactions::create_pool::create_pool;
+use crate::transactions::deposit_rewards::deposit_rewards;
+use crate::transactions::force_unstake::force_unstake;
 use crate::transactions::generate_vault::generate_vault;
+use crate::transactions::initialize_platform::initialize_platform;
+use crate::transactions::propose_admin::propose_admin;
+use crate::transactions::remove_from_whitelist::remove_from_whitelist;
+use crate::transactions::request_unstake::request_unstake;
+use crate::transactions::set_boost_root::set_boost_root;
+use crate::transactions::set_lock_tiers::set_lock_tiers;
+use crate::transactions::set_pause_flags::set_pause_flags;
+use crate::transactions::set_pool_options::set_pool_options;
 use crate::transactions::stake::stake;
+use crate::transactions::stake_batch::stake_batch;
+use crate::transactions::transfer_position::transfer_position;
 use crate::transactions::unstake::unstake;
+use crate::transactions::unstake_batch::unstake_batch;
+use crate::transactions::update_reward_schedule::update_reward_schedule;
+use crate::transactions::update_whitelist::update_whitelist;
+use crate::transactions::withdraw_surplus::withdraw_surplus;
 use clap::{
     app_from_crate, crate_authors, crate_description, crate_name, crate_version, Arg, SubCommand,
 };
@@ -14,7 +44,594 @@ use clap::{
 fn main() {
     let matches = app_from_crate!()
         .subcommand(
-            SubCommand::with_name("generate_vault_address")
+            SubCommand::with_name("initialize_platform")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("propose_admin")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("new_admin")
+                        .short("a")
+                        .long("new-admin")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("accept_admin")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set_pause_flags")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(Arg::with_name("stake").long("stake").takes_value(false))
+                .arg(Arg::with_name("claim").long("claim").takes_value(false))
+                .arg(Arg::with_name("unstake").long("unstake").takes_value(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("create_pool")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("reward_mint")
+                        .short("r")
+                        .long("reward-mint")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("interval")
+                        .long("interval")
+                        .default_value(SECONDS_IN_THE_DAY)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("payout_per_interval")
+                        .long("payout-per-interval")
+                        .default_value(PAYOUT_PER_DAY)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("warmup_intervals")
+                        .long("warmup-intervals")
+                        .default_value(WARMUP_PERIOD)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("period_intervals")
+                        .long("period-intervals")
+                        .default_value(REWARD_PERIOD)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("max_payout_per_nft")
+                        .long("max-payout-per-nft")
+                        .default_value(MAX_PAYOUT_PER_NFT)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("update_reward_schedule")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("interval")
+                        .long("interval")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("payout_per_interval")
+                        .long("payout-per-interval")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("warmup_intervals")
+                        .long("warmup-intervals")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("period_intervals")
+                        .long("period-intervals")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("max_payout_per_nft")
+                        .long("max-payout-per-nft")
+                        .required(false)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set_boost_root")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("hashlist")
+                        .long("hashlist")
+                        .required_unless("clear")
+                        .conflicts_with("clear")
+                        .takes_value(true),
+                )
+                .arg(Arg::with_name("clear").long("clear").takes_value(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("set_pool_options")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("require_funded")
+                        .long("require-funded")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("max_staked")
+                        .long("max-staked")
+                        .default_value(NFT_AMOUNT)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("soft_staking")
+                        .long("soft-staking")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("early_exit_penalty")
+                        .long("early-exit-penalty")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("unbonding_period")
+                        .long("unbonding-period")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("settle_on_transfer")
+                        .long("settle-on-transfer")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("mint_receipts")
+                        .long("mint-receipts")
+                        .takes_value(false),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set_lock_tiers")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("tiers")
+                        .long("tiers")
+                        .required_unless("clear")
+                        .conflicts_with("clear")
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .takes_value(true),
+                )
+                .arg(Arg::with_name("clear").long("clear").takes_value(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("generate_vault_address")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("deposit_rewards")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("amount")
+                        .short("a")
+                        .long("amount")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("withdraw_surplus")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("amount")
+                        .short("a")
+                        .long("amount")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("add_to_whitelist")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("creator")
+                        .short("c")
+                        .long("creator")
+                        .required_unless("collection")
+                        .conflicts_with("collection")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("collection")
+                        .long("collection")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("multiplier")
+                        .short("m")
+                        .long("multiplier")
+                        .default_value(BASIS_POINTS)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("remove_from_whitelist")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("creator")
+                        .short("c")
+                        .long("creator")
+                        .required_unless("collection")
+                        .conflicts_with("collection")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("collection")
+                        .long("collection")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("update_whitelist")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("creator")
+                        .short("c")
+                        .long("creator")
+                        .required_unless("collection")
+                        .conflicts_with("collection")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("collection")
+                        .long("collection")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("multiplier")
+                        .short("m")
+                        .long("multiplier")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("enable")
+                        .long("enable")
+                        .conflicts_with("disable")
+                        .takes_value(false),
+                )
+                .arg(Arg::with_name("disable").long("disable").takes_value(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("stake")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nft")
+                        .short("n")
+                        .long("nft")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("collection")
+                        .long("collection")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("hashlist")
+                        .long("hashlist")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("lock_tier")
+                        .long("lock-tier")
+                        .required(false)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("stake_batch")
                 .arg(
                     Arg::with_name("sign")
                         .short("s")
@@ -28,10 +645,43 @@ fn main() {
                         .long("env")
                         .required(false)
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nfts")
+                        .short("n")
+                        .long("nfts")
+                        .required(true)
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("collection")
+                        .long("collection")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("hashlist")
+                        .long("hashlist")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("lock_tier")
+                        .long("lock-tier")
+                        .required(false)
+                        .takes_value(true),
                 ),
         )
         .subcommand(
-            SubCommand::with_name("add_to_whitelist")
+            SubCommand::with_name("unstake_batch")
                 .arg(
                     Arg::with_name("sign")
                         .short("s")
@@ -47,15 +697,115 @@ fn main() {
                         .takes_value(true),
                 )
                 .arg(
-                    Arg::with_name("creator")
-                        .short("c")
-                        .long("creator")
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nfts")
+                        .short("n")
+                        .long("nfts")
                         .required(true)
+                        .multiple(true)
+                        .use_delimiter(true)
                         .takes_value(true),
                 ),
         )
         .subcommand(
-            SubCommand::with_name("stake")
+            SubCommand::with_name("force_unstake")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nfts")
+                        .short("n")
+                        .long("nfts")
+                        .required(true)
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .takes_value(true),
+                )
+                .arg(Arg::with_name("settle").long("settle").takes_value(false)),
+        )
+        .subcommand(
+            SubCommand::with_name("claim_all")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("claim_batch")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nfts")
+                        .short("n")
+                        .long("nfts")
+                        .required(true)
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("unstake")
                 .arg(
                     Arg::with_name("sign")
                         .short("s")
@@ -70,16 +820,91 @@ fn main() {
                         .required(false)
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("nft")
                         .short("n")
                         .long("nft")
                         .required(true)
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("recipient")
+                        .long("recipient")
+                        .required(false)
+                        .takes_value(true),
                 ),
         )
         .subcommand(
-            SubCommand::with_name("unstake")
+            SubCommand::with_name("request_unstake")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nft")
+                        .short("n")
+                        .long("nft")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("complete_unstake")
+                .arg(
+                    Arg::with_name("sign")
+                        .short("s")
+                        .long("sign")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .short("e")
+                        .long("env")
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("nft")
+                        .short("n")
+                        .long("nft")
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("transfer_position")
                 .arg(
                     Arg::with_name("sign")
                         .short("s")
@@ -94,12 +919,31 @@ fn main() {
                         .required(false)
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("nft")
                         .short("n")
                         .long("nft")
                         .required(true)
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("to")
+                        .long("to")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("to_sign")
+                        .long("to-sign")
+                        .required(false)
+                        .takes_value(true),
                 ),
         )
         .subcommand(
@@ -118,12 +962,25 @@ fn main() {
                         .required(false)
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("pool")
+                        .short("p")
+                        .long("pool")
+                        .required(true)
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("nft")
                         .short("n")
                         .long("nft")
                         .required(true)
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("recipient")
+                        .long("recipient")
+                        .required(false)
+                        .takes_value(true),
                 ),
         )
         .get_matches();
@@ -136,15 +993,99 @@ fn main() {
         unstake(matches);
     }
 
+    if let Some(matches) = matches.subcommand_matches("request_unstake") {
+        request_unstake(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("complete_unstake") {
+        complete_unstake(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("transfer_position") {
+        transfer_position(matches);
+    }
+
     if let Some(matches) = matches.subcommand_matches("stake") {
         stake(matches);
     }
 
+    if let Some(matches) = matches.subcommand_matches("stake_batch") {
+        stake_batch(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("unstake_batch") {
+        unstake_batch(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("force_unstake") {
+        force_unstake(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("claim_all") {
+        claim_all(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("claim_batch") {
+        claim_batch(matches);
+    }
+
     if let Some(matches) = matches.subcommand_matches("add_to_whitelist") {
         add_to_whitelist(matches);
     }
 
+    if let Some(matches) = matches.subcommand_matches("remove_from_whitelist") {
+        remove_from_whitelist(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("update_whitelist") {
+        update_whitelist(matches);
+    }
+
     if let Some(matches) = matches.subcommand_matches("generate_vault_address") {
         generate_vault(matches);
     }
+
+    if let Some(matches) = matches.subcommand_matches("deposit_rewards") {
+        deposit_rewards(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("withdraw_surplus") {
+        withdraw_surplus(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("set_pool_options") {
+        set_pool_options(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("set_lock_tiers") {
+        set_lock_tiers(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("initialize_platform") {
+        initialize_platform(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("create_pool") {
+        create_pool(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("update_reward_schedule") {
+        update_reward_schedule(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("set_boost_root") {
+        set_boost_root(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("set_pause_flags") {
+        set_pause_flags(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("propose_admin") {
+        propose_admin(matches);
+    }
+
+    if let Some(matches) = matches.subcommand_matches("accept_admin") {
+        accept_admin(matches);
+    }
 }
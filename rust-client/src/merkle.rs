@@ -0,0 +1,95 @@
+use crate::structs::Boost;
+use solana_sdk::keccak::hashv;
+use solana_sdk::pubkey::Pubkey;
+use std::fs;
+
+/// Merkle tree of `(mint, boost)` pairs, mirrors the program's `state::merkle`
+pub struct BoostTree {
+    pub boosts: Vec<(Pubkey, u64)>,
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+fn boost_leaf(mint: &Pubkey, boost: u64) -> [u8; 32] {
+    hashv(&[&[0], &mint.to_bytes(), &boost.to_le_bytes()]).to_bytes()
+}
+
+fn boost_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        hashv(&[&[1], left, right]).to_bytes()
+    } else {
+        hashv(&[&[1], right, left]).to_bytes()
+    }
+}
+
+impl BoostTree {
+    /// Reads a hashlist with one `<mint>,<boost-bps>` pair per line
+    pub fn from_csv(path: &str) -> BoostTree {
+        let csv = fs::read_to_string(path).expect("Can't open hashlist");
+
+        let boosts = csv
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with("mint"))
+            .map(|line| {
+                let mut columns = line.split(',');
+                let mint = columns.next().unwrap().trim().parse::<Pubkey>().unwrap();
+                let boost = columns.next().unwrap().trim().parse::<u64>().unwrap();
+                (mint, boost)
+            })
+            .collect();
+
+        BoostTree::new(boosts)
+    }
+
+    pub fn new(boosts: Vec<(Pubkey, u64)>) -> BoostTree {
+        let mut levels = vec![boosts
+            .iter()
+            .map(|(mint, boost)| boost_leaf(mint, *boost))
+            .collect::<Vec<_>>()];
+
+        while levels.last().unwrap().len() > 1 {
+            // an odd node is promoted to the next level as is
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => boost_node(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        BoostTree { boosts, levels }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        *self
+            .levels
+            .last()
+            .unwrap()
+            .first()
+            .expect("Hashlist is empty")
+    }
+
+    /// Boost of `mint` with its proof, `None` if the mint is not in the hashlist
+    pub fn proof(&self, mint: &Pubkey) -> Option<Boost> {
+        let position = self.boosts.iter().position(|(m, _)| m == mint)?;
+        let mut index = position;
+        let mut proof = vec![];
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        Some(Boost {
+            boost: self.boosts[position].1,
+            proof,
+        })
+    }
+}
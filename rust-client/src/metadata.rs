@@ -0,0 +1,45 @@
+use crate::structs::Eligibility;
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_metadata::state::Metadata;
+
+#[derive(BorshDeserialize)]
+struct Collection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// Key of the whitelist entry an NFT is staked under, mirrors the program's `get_whitelist_key`
+pub fn get_whitelist_key(metadata_data: &[u8], eligibility: Eligibility) -> Pubkey {
+    let mut data = metadata_data;
+
+    let metadata = Metadata::deserialize(&mut data).expect("Can't parse metadata");
+
+    match eligibility {
+        Eligibility::Creator => metadata.data.creators.unwrap().first().unwrap().address,
+        Eligibility::Collection => {
+            let _token_standard = Option::<u8>::deserialize(&mut data).unwrap();
+            let collection = Option::<Collection>::deserialize(&mut data)
+                .unwrap()
+                .expect("NFT has no collection");
+            if !collection.verified {
+                panic!("NFT collection is not verified");
+            }
+            collection.key
+        }
+    }
+}
+
+/// Master edition of an NFT, token-metadata freezes and thaws soft staked NFTs through it
+pub fn get_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            "metadata".as_bytes(),
+            &spl_token_metadata::ID.to_bytes(),
+            &mint.to_bytes(),
+            "edition".as_bytes(),
+        ],
+        &spl_token_metadata::ID,
+    )
+    .0
+}
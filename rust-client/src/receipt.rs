@@ -0,0 +1,76 @@
+use crate::structs::StakeData;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcProgramAccountsConfig;
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_sdk::borsh::try_from_slice_unchecked;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::Account;
+
+/// Receipt mint of the `stakes`-th position of `nft`, mirrors the program's `get_receipt_address`
+pub fn get_receipt_address(
+    program_id: &Pubkey,
+    pool: &Pubkey,
+    nft: &Pubkey,
+    stakes: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            "receipt".as_bytes(),
+            &pool.to_bytes(),
+            &nft.to_bytes(),
+            &stakes.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Receipt token account of `wallet` for the `stakes`-th position of `nft`
+pub fn get_receipt_holder(
+    program_id: &Pubkey,
+    pool: &Pubkey,
+    nft: &Pubkey,
+    stakes: u64,
+    wallet: &Pubkey,
+) -> Pubkey {
+    spl_associated_token_account::get_associated_token_address(
+        wallet,
+        &get_receipt_address(program_id, pool, nft, stakes),
+    )
+}
+
+/// Times `nft` was staked in the pool, 0 before its first stake
+pub fn get_stakes(client: &RpcClient, program_id: &Pubkey, pool: &Pubkey, nft: &Pubkey) -> u64 {
+    let (stake_data, _) =
+        Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], program_id);
+
+    match client.get_account_data(&stake_data) {
+        Ok(data) => try_from_slice_unchecked::<StakeData>(&data).unwrap().stakes,
+        Err(_) => 0,
+    }
+}
+
+/// Token account holding the `receipt` and the wallet owning it
+pub fn find_receipt_holder(client: &RpcClient, receipt: &Pubkey) -> (Pubkey, Pubkey) {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(Account::LEN as u64),
+            RpcFilterType::Memcmp(Memcmp {
+                offset: 0,
+                bytes: MemcmpEncodedBytes::Base58(receipt.to_string()),
+                encoding: None,
+            }),
+        ]),
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    client
+        .get_program_accounts_with_config(&spl_token::id(), config)
+        .unwrap()
+        .into_iter()
+        .map(|(holder, account)| (holder, Account::unpack(&account.data).unwrap()))
+        .find(|(_, account)| account.amount == 1)
+        .map(|(holder, account)| (holder, account.owner))
+        .expect("Receipt is not held by anyone")
+}
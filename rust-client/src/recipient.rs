@@ -0,0 +1,35 @@
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+/// Reward token account paid by `ClaimTo` and `UnstakeTo`, a wallet gets paid into its
+/// associated token account, created by `payer` when missing
+pub fn get_recipient(
+    client: &RpcClient,
+    payer: &Pubkey,
+    address: &Pubkey,
+    reward_mint: &Pubkey,
+) -> (Pubkey, Vec<Instruction>) {
+    if let Ok(account) = client.get_account(address) {
+        if account.owner == spl_token::id() {
+            return (*address, vec![]);
+        }
+    }
+
+    let holder = spl_associated_token_account::get_associated_token_address(address, reward_mint);
+
+    if client.get_account(&holder).is_ok() {
+        return (holder, vec![]);
+    }
+
+    (
+        holder,
+        vec![
+            spl_associated_token_account::create_associated_token_account(
+                payer,
+                address,
+                reward_mint,
+            ),
+        ],
+    )
+}
@@ -1,21 +1,226 @@
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use solana_sdk::pubkey::Pubkey;
 
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
 pub enum PlatformInstruction {
-    GenerateVault,
-    AddToWhitelist,
-    Stake,
-    Unstake,
-    Claim,
+    GenerateVault {
+        pool_id: u64,
+    },
+    AddToWhitelist {
+        pool_id: u64,
+        eligibility: Eligibility,
+        reward_multiplier: u64,
+    },
+    Stake {
+        pool_id: u64,
+        eligibility: Eligibility,
+        boost: Option<Boost>,
+        lock_tier: Option<u8>,
+    },
+    Unstake {
+        pool_id: u64,
+    },
+    Claim {
+        pool_id: u64,
+    },
+    InitializePlatform,
+    ProposeAdmin {
+        new_admin: Pubkey,
+    },
+    AcceptAdmin,
+    CreatePool {
+        pool_id: u64,
+        schedule: RewardSchedule,
+    },
+    UpdateRewardSchedule {
+        pool_id: u64,
+        schedule: RewardSchedule,
+    },
+    SetPauseFlags {
+        paused: PauseFlags,
+    },
+    RemoveFromWhitelist {
+        pool_id: u64,
+    },
+    UpdateWhitelist {
+        pool_id: u64,
+        reward_multiplier: u64,
+        enabled: bool,
+    },
+    SetBoostRoot {
+        pool_id: u64,
+        root: Option<[u8; 32]>,
+    },
+    DepositRewards {
+        pool_id: u64,
+        amount: u64,
+    },
+    SetPoolOptions {
+        pool_id: u64,
+        options: PoolOptions,
+    },
+    WithdrawSurplus {
+        pool_id: u64,
+        amount: u64,
+    },
+    StakeBatch {
+        pool_id: u64,
+        entries: Vec<StakeEntry>,
+    },
+    UnstakeBatch {
+        pool_id: u64,
+        count: u8,
+    },
+    ClaimBatch {
+        pool_id: u64,
+        count: u8,
+    },
+    ClaimAll {
+        pool_id: u64,
+    },
+    SetLockTiers {
+        pool_id: u64,
+        tiers: Vec<LockTier>,
+    },
+    RequestUnstake {
+        pool_id: u64,
+    },
+    CompleteUnstake {
+        pool_id: u64,
+    },
+    ClaimTo {
+        pool_id: u64,
+    },
+    UnstakeTo {
+        pool_id: u64,
+    },
+    TransferPosition {
+        pool_id: u64,
+    },
+    ForceUnstake {
+        pool_id: u64,
+        count: u8,
+        settle: bool,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
-struct StakeData {
+pub struct StakeData {
     timestamp: u64,
-    staker: Pubkey,
+    pub staker: Pubkey,
     mint: Pubkey,
-    active: bool,
+    pub active: bool,
     withdrawn: u64,
     harvested: u64,
+    pub accrued: u64,
+    checkpoint: u64,
+    pub whitelist: Pubkey,
+    reward_multiplier: u64,
+    boost: u64,
+    reserved: u64,
+    pub frozen: bool,
+    pub lock_until: u64,
+    lock_multiplier: u64,
+    unbonding_until: u64,
+    pub receipt: bool,
+    pub stakes: u64,
+    raised_at: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct StakeEntry {
+    pub eligibility: Eligibility,
+    pub boost: Option<Boost>,
+    pub lock_tier: Option<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Boost {
+    pub boost: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum Eligibility {
+    Creator,
+    Collection,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct WhitelistData {
+    pub added_at: u64,
+    pub added_by: Pubkey,
+    pub enabled: bool,
+    pub reward_multiplier: u64,
+    pub eligibility: Eligibility,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PauseFlags {
+    pub stake: bool,
+    pub claim: bool,
+    pub unstake: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct StakerData {
+    pub staker: Pubkey,
+    pub positions: u64,
+    pub rewards_claimed: u64,
+    pub mints: Vec<Pubkey>,
+    pub owed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct Pool {
+    pub pool_id: u64,
+    pub reward_mint: Pubkey,
+    pub vault_bump: u8,
+    pub boost_root: Option<[u8; 32]>,
+    pub options: PoolOptions,
+    pub ledger: RewardLedger,
+    pub lock_tiers: Vec<LockTier>,
+    pub epochs: Vec<ScheduleEpoch>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct LockTier {
+    pub duration: u64,
+    pub multiplier: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct PoolOptions {
+    pub require_funded: bool,
+    pub max_staked: u64,
+    pub soft_staking: bool,
+    pub early_exit_penalty: Option<u64>,
+    pub unbonding_period: u64,
+    pub settle_on_transfer: bool,
+    pub mint_receipts: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct RewardLedger {
+    pub total_deposited: u64,
+    pub total_paid: u64,
+    pub liability: u64,
+    pub total_withdrawn: u64,
+    pub active_multiplier: u64,
+    pub raised: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ScheduleEpoch {
+    pub start: u64,
+    pub schedule: RewardSchedule,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct RewardSchedule {
+    pub interval: u64,
+    pub payout_per_interval: u64,
+    pub warmup_intervals: u64,
+    pub period_intervals: u64,
+    pub max_payout_per_nft: u64,
 }
@@ -1,5 +1,5 @@
 use crate::consts::{PROGRAM_ID, RENT};
-use crate::structs::PlatformInstruction;
+use crate::structs::{Eligibility, PlatformInstruction};
 use clap::ArgMatches;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::commitment_config::CommitmentConfig;
@@ -26,25 +26,59 @@ pub fn add_to_whitelist(matches: &ArgMatches) {
     let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
     let wallet_pubkey = wallet_keypair.pubkey();
 
-    let creator = matches
-        .value_of("creator")
+    let (creator, eligibility) = match matches.value_of("collection") {
+        Some(collection) => (
+            collection.parse::<Pubkey>().unwrap(),
+            Eligibility::Collection,
+        ),
+        None => (
+            matches
+                .value_of("creator")
+                .unwrap()
+                .parse::<Pubkey>()
+                .unwrap(),
+            Eligibility::Creator,
+        ),
+    };
+
+    let reward_multiplier = matches
+        .value_of("multiplier")
         .unwrap()
-        .parse::<Pubkey>()
+        .parse::<u64>()
         .unwrap();
 
-    let (wl_address, _) =
-        Pubkey::find_program_address(&["whitelist".as_bytes(), &creator.to_bytes()], &program_id);
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (wl_address, _) = Pubkey::find_program_address(
+        &[
+            "whitelist".as_bytes(),
+            &pool.to_bytes(),
+            &creator.to_bytes(),
+        ],
+        &program_id,
+    );
     println!("{:?}", wl_address.clone());
 
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
     let instructions = vec![Instruction::new_with_borsh(
         program_id,
-        &PlatformInstruction::AddToWhitelist,
+        &PlatformInstruction::AddToWhitelist {
+            pool_id,
+            eligibility,
+            reward_multiplier,
+        },
         vec![
             AccountMeta::new(wallet_pubkey, true),
             AccountMeta::new(creator, false),
             AccountMeta::new(wl_address, false),
             AccountMeta::new(system_program::id(), false),
             AccountMeta::new_readonly(RENT.parse::<Pubkey>().unwrap(), false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(pool, false),
         ],
     )];
 
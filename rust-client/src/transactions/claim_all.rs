@@ -0,0 +1,137 @@
+use crate::batch::pack_batch;
+use crate::consts::{ASSOCIATED_TOKEN, PROGRAM_ID, RENT};
+use crate::structs::{PlatformInstruction, Pool, StakeData, StakerData};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::borsh::try_from_slice_unchecked;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn claim_all(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (stats, _) =
+        Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let (staker, _) = Pubkey::find_program_address(
+        &[
+            "staker".as_bytes(),
+            &pool.to_bytes(),
+            &wallet_pubkey.to_bytes(),
+        ],
+        &program_id,
+    );
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let pool_data = client.get_account_data(&pool).unwrap();
+    let reward_mint = try_from_slice_unchecked::<Pool>(&pool_data)
+        .unwrap()
+        .reward_mint;
+
+    let staker_data = client.get_account_data(&staker).unwrap();
+    let mints = try_from_slice_unchecked::<StakerData>(&staker_data)
+        .unwrap()
+        .mints;
+
+    let (vault, _vault_bump) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let reward_destination =
+        spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &reward_mint);
+
+    let reward_source =
+        spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
+
+    let accounts = vec![
+        AccountMeta::new(wallet_pubkey, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(RENT.parse::<Pubkey>().unwrap(), false),
+        AccountMeta::new_readonly(ASSOCIATED_TOKEN.parse::<Pubkey>().unwrap(), false),
+        AccountMeta::new_readonly(vault, false),
+        AccountMeta::new(reward_destination, false),
+        AccountMeta::new(reward_source, false),
+        AccountMeta::new_readonly(reward_mint, false),
+        AccountMeta::new(pool, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new(stats, false),
+        AccountMeta::new(staker, false),
+    ];
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut stake_datas = vec![];
+
+    for nft in mints {
+        let (stake_data, _) =
+            Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &program_id);
+
+        // requested unstakes are paid out already and wait for `complete_unstake`
+        let stake_data_data = client.get_account_data(&stake_data).unwrap();
+        let position = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+        if !position.active {
+            continue;
+        }
+
+        // locked positions are paid out when they are unstaked
+        if position.active && now < position.lock_until {
+            continue;
+        }
+
+        stake_datas.push(AccountMeta::new(stake_data, false));
+    }
+
+    let build = |range: std::ops::Range<usize>| {
+        let mut accounts = accounts.clone();
+        accounts.extend_from_slice(&stake_datas[range]);
+
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::ClaimAll { pool_id },
+            accounts,
+        )
+    };
+
+    // the first transaction also pays what is owed, it goes out even without positions
+    let instructions = if stake_datas.is_empty() {
+        vec![build(0..0)]
+    } else {
+        pack_batch(&wallet_pubkey, stake_datas.len(), build).unwrap()
+    };
+
+    for instruction in instructions {
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&wallet_pubkey));
+        let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+        tx.sign(&vec![&wallet_keypair], recent_blockhash);
+        let id = client.send_transaction(&tx).expect("Transaction failed.");
+        println!("tx id: {:?}", id);
+    }
+}
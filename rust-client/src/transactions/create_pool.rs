@@ -0,0 +1,93 @@
+use crate::consts::{PROGRAM_ID, RENT};
+use crate::structs::{PlatformInstruction, RewardSchedule};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+
+pub fn create_pool(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let reward_mint = matches
+        .value_of("reward_mint")
+        .unwrap()
+        .parse::<Pubkey>()
+        .unwrap();
+
+    let schedule = RewardSchedule {
+        interval: matches
+            .value_of("interval")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+        payout_per_interval: matches
+            .value_of("payout_per_interval")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+        warmup_intervals: matches
+            .value_of("warmup_intervals")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+        period_intervals: matches
+            .value_of("period_intervals")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+        max_payout_per_nft: matches
+            .value_of("max_payout_per_nft")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+    };
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (stats, _) =
+        Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let instructions = vec![Instruction::new_with_borsh(
+        program_id,
+        &PlatformInstruction::CreatePool { pool_id, schedule },
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(RENT.parse::<Pubkey>().unwrap(), false),
+            AccountMeta::new(stats, false),
+        ],
+    )];
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
+    let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+    tx.sign(&vec![&wallet_keypair], recent_blockhash);
+    let id = client.send_transaction(&tx).expect("Transaction failed.");
+    println!("pool: {:?}", pool);
+    println!("tx id: {:?}", id);
+}
@@ -0,0 +1,74 @@
+use crate::consts::{ASSOCIATED_TOKEN, PROGRAM_ID, RENT};
+use crate::structs::{PlatformInstruction, Pool};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::borsh::try_from_slice_unchecked;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+
+pub fn deposit_rewards(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let amount = matches.value_of("amount").unwrap().parse::<u64>().unwrap();
+
+    let pool_data = client.get_account_data(&pool).unwrap();
+    let reward_mint = try_from_slice_unchecked::<Pool>(&pool_data)
+        .unwrap()
+        .reward_mint;
+
+    let (vault, _) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let source =
+        spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &reward_mint);
+
+    let destination =
+        spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
+
+    let instructions = vec![Instruction::new_with_borsh(
+        program_id,
+        &PlatformInstruction::DepositRewards { pool_id, amount },
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new(source, false),
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(RENT.parse::<Pubkey>().unwrap(), false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN.parse::<Pubkey>().unwrap(), false),
+        ],
+    )];
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
+    let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+    tx.sign(&vec![&wallet_keypair], recent_blockhash);
+    let id = client.send_transaction(&tx).expect("Transaction failed.");
+    println!("deposited: {:?}", amount);
+    println!("tx id: {:?}", id);
+}
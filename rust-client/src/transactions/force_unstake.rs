@@ -0,0 +1,167 @@
+use crate::batch::{join_batch, pack_batch, unused_account, FORCE_UNSTAKE_GROUP};
+use crate::consts::{ASSOCIATED_TOKEN, PROGRAM_ID, RENT};
+use crate::metadata::get_edition_address;
+use crate::receipt::{find_receipt_holder, get_receipt_address, get_receipt_holder};
+use crate::structs::{PlatformInstruction, Pool, StakeData};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::borsh::try_from_slice_unchecked;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+
+pub fn force_unstake(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let nfts: Vec<Pubkey> = matches
+        .values_of("nfts")
+        .unwrap()
+        .map(|nft| nft.parse::<Pubkey>().unwrap())
+        .collect();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (stats, _) =
+        Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let settle = matches.is_present("settle");
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let pool_data = client.get_account_data(&pool).unwrap();
+    let reward_mint = try_from_slice_unchecked::<Pool>(&pool_data)
+        .unwrap()
+        .reward_mint;
+
+    let (vault, _vault_bump) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let reward_source =
+        spl_associated_token_account::get_associated_token_address(&vault, &reward_mint);
+
+    let mut lists = vec![];
+
+    for nft in nfts.iter() {
+        let (stake_data, _) =
+            Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &program_id);
+
+        let stake_data_data = client.get_account_data(&stake_data).unwrap();
+        let position = try_from_slice_unchecked::<StakeData>(&stake_data_data).unwrap();
+
+        let receipt = get_receipt_address(&program_id, &pool, nft, position.stakes);
+
+        // the NFT and the reward go back to the wallet recorded in the position,
+        // or to whoever holds the receipt of a receipt position
+        let (receipt_holder, owner) = if position.receipt {
+            find_receipt_holder(&client, &receipt)
+        } else {
+            (
+                get_receipt_holder(&program_id, &pool, nft, position.stakes, &position.staker),
+                position.staker,
+            )
+        };
+
+        let destination = spl_associated_token_account::get_associated_token_address(&owner, nft);
+
+        let source = spl_associated_token_account::get_associated_token_address(&vault, nft);
+
+        // the reward holder is only paid when settling
+        let reward_destination = if settle {
+            AccountMeta::new(
+                spl_associated_token_account::get_associated_token_address(&owner, &reward_mint),
+                false,
+            )
+        } else {
+            unused_account()
+        };
+
+        let edition = if position.frozen {
+            AccountMeta::new_readonly(get_edition_address(nft), false)
+        } else {
+            unused_account()
+        };
+
+        let (receipt, receipt_holder) = if position.receipt {
+            (
+                AccountMeta::new(receipt, false),
+                AccountMeta::new(receipt_holder, false),
+            )
+        } else {
+            (unused_account(), unused_account())
+        };
+
+        let (staker, _) = Pubkey::find_program_address(
+            &["staker".as_bytes(), &pool.to_bytes(), &owner.to_bytes()],
+            &program_id,
+        );
+
+        lists.push(vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*nft, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(RENT.parse::<Pubkey>().unwrap(), false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN.parse::<Pubkey>().unwrap(), false),
+            AccountMeta::new(stake_data, false),
+            AccountMeta::new(vault, false),
+            reward_destination,
+            AccountMeta::new(reward_source, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new(source, false),
+            // forced unstakes don't read the metadata and whitelist
+            unused_account(),
+            unused_account(),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(stats, false),
+            AccountMeta::new(staker, false),
+            edition,
+            AccountMeta::new_readonly(spl_token_metadata::ID, false),
+            receipt,
+            receipt_holder,
+            AccountMeta::new(owner, false),
+        ]);
+    }
+
+    let instructions = pack_batch(&wallet_pubkey, nfts.len(), |range| {
+        Instruction::new_with_borsh(
+            program_id,
+            &PlatformInstruction::ForceUnstake {
+                pool_id,
+                count: range.len() as u8,
+                settle,
+            },
+            join_batch(&lists[range], &FORCE_UNSTAKE_GROUP),
+        )
+    })
+    .unwrap_or_else(|nft| panic!("{} doesn't fit in a transaction", nfts[nft]));
+
+    for instruction in instructions {
+        let mut tx = Transaction::new_with_payer(&[instruction], Some(&wallet_pubkey));
+        let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+        tx.sign(&vec![&wallet_keypair], recent_blockhash);
+        let id = client.send_transaction(&tx).expect("Transaction failed.");
+        println!("tx id: {:?}", id);
+    }
+}
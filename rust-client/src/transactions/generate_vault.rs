@@ -26,16 +26,26 @@ pub fn generate_vault(matches: &ArgMatches) {
     let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
     let wallet_pubkey = wallet_keypair.pubkey();
 
-    let (vault_pda, _) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
 
     let instructions = vec![Instruction::new_with_borsh(
         program_id,
-        &PlatformInstruction::GenerateVault,
+        &PlatformInstruction::GenerateVault { pool_id },
         vec![
             AccountMeta::new(wallet_pubkey, true),
             AccountMeta::new(system_program::id(), false),
             AccountMeta::new(vault_pda, false),
             AccountMeta::new_readonly(RENT.parse::<Pubkey>().unwrap(), false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(pool, false),
         ],
     )];
     let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
@@ -1,5 +1,26 @@
+pub mod accept_admin;
 pub mod add_to_whitelist;
 pub mod claim;
+pub mod claim_all;
+pub mod claim_batch;
+pub mod complete_unstake;
+pub mod create_pool;
+pub mod deposit_rewards;
+pub mod force_unstake;
 pub mod generate_vault;
+pub mod initialize_platform;
+pub mod propose_admin;
+pub mod remove_from_whitelist;
+pub mod request_unstake;
+pub mod set_boost_root;
+pub mod set_lock_tiers;
+pub mod set_pause_flags;
+pub mod set_pool_options;
 pub mod stake;
+pub mod stake_batch;
+pub mod transfer_position;
 pub mod unstake;
+pub mod unstake_batch;
+pub mod update_reward_schedule;
+pub mod update_whitelist;
+pub mod withdraw_surplus;
@@ -0,0 +1,72 @@
+use crate::consts::{PROGRAM_ID, SECONDS_IN_THE_DAY};
+use crate::structs::{LockTier, PlatformInstruction};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::transaction::Transaction;
+
+pub fn set_lock_tiers(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let day = SECONDS_IN_THE_DAY.parse::<u64>().unwrap();
+
+    // each tier is `<days>:<multiplier in basis points>`
+    let tiers = matches
+        .values_of("tiers")
+        .map(|tiers| {
+            tiers
+                .map(|tier| {
+                    let (days, multiplier) = tier.split_once(':').expect("Tier must be days:bps");
+                    LockTier {
+                        duration: days.parse::<u64>().unwrap() * day,
+                        multiplier: multiplier.parse::<u64>().unwrap(),
+                    }
+                })
+                .collect::<Vec<LockTier>>()
+        })
+        .unwrap_or_default();
+
+    let instructions = vec![Instruction::new_with_borsh(
+        program_id,
+        &PlatformInstruction::SetLockTiers {
+            pool_id,
+            tiers: tiers.clone(),
+        },
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(pool, false),
+        ],
+    )];
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
+    let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+    tx.sign(&vec![&wallet_keypair], recent_blockhash);
+    let id = client.send_transaction(&tx).expect("Transaction failed.");
+    println!("lock tiers: {:?}", tiers);
+    println!("tx id: {:?}", id);
+}
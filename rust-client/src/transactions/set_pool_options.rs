@@ -0,0 +1,71 @@
+use crate::consts::PROGRAM_ID;
+use crate::structs::{PlatformInstruction, PoolOptions};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::transaction::Transaction;
+
+pub fn set_pool_options(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let options = PoolOptions {
+        require_funded: matches.is_present("require_funded"),
+        max_staked: matches
+            .value_of("max_staked")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+        soft_staking: matches.is_present("soft_staking"),
+        early_exit_penalty: matches
+            .value_of("early_exit_penalty")
+            .map(|penalty| penalty.parse::<u64>().unwrap()),
+        unbonding_period: matches
+            .value_of("unbonding_period")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+        settle_on_transfer: matches.is_present("settle_on_transfer"),
+        mint_receipts: matches.is_present("mint_receipts"),
+    };
+
+    let instructions = vec![Instruction::new_with_borsh(
+        program_id,
+        &PlatformInstruction::SetPoolOptions { pool_id, options },
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(pool, false),
+        ],
+    )];
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
+    let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+    tx.sign(&vec![&wallet_keypair], recent_blockhash);
+    let id = client.send_transaction(&tx).expect("Transaction failed.");
+    println!("options: {:?}", options);
+    println!("tx id: {:?}", id);
+}
@@ -1,8 +1,10 @@
 use crate::consts::{ASSOCIATED_TOKEN, PROGRAM_ID, RENT};
-use crate::structs::PlatformInstruction;
+use crate::merkle::BoostTree;
+use crate::metadata::{get_edition_address, get_whitelist_key};
+use crate::receipt::{get_receipt_address, get_receipt_holder, get_stakes};
+use crate::structs::{Eligibility, PlatformInstruction};
 use clap::ArgMatches;
 use solana_client::rpc_client::RpcClient;
-use solana_sdk::borsh::try_from_slice_unchecked;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
@@ -29,6 +31,25 @@ pub fn stake(matches: &ArgMatches) {
 
     let nft = matches.value_of("nft").unwrap().parse::<Pubkey>().unwrap();
 
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (stats, _) =
+        Pubkey::find_program_address(&["stats".as_bytes(), &pool.to_bytes()], &program_id);
+
+    let (staker, _) = Pubkey::find_program_address(
+        &[
+            "staker".as_bytes(),
+            &pool.to_bytes(),
+            &wallet_pubkey.to_bytes(),
+        ],
+        &program_id,
+    );
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
     let (metadata, _) = Pubkey::find_program_address(
         &[
             "metadata".as_bytes(),
@@ -38,38 +59,61 @@ pub fn stake(matches: &ArgMatches) {
         &spl_token_metadata::ID,
     );
 
-    let (vault, _vault_bump) = Pubkey::find_program_address(&["vault".as_bytes()], &program_id);
+    let (vault, _vault_bump) =
+        Pubkey::find_program_address(&["vault".as_bytes(), &pool.to_bytes()], &program_id);
 
     let source = spl_associated_token_account::get_associated_token_address(&wallet_pubkey, &nft);
 
     let destination = spl_associated_token_account::get_associated_token_address(&vault, &nft);
 
-    let (stake_data, _) = Pubkey::find_program_address(&[&nft.to_bytes()], &program_id);
+    let (stake_data, _) =
+        Pubkey::find_program_address(&[&pool.to_bytes(), &nft.to_bytes()], &program_id);
 
     let metadata_data = client.get_account_data(&metadata).unwrap();
 
-    let metadata_data_struct: spl_token_metadata::state::Metadata =
-        try_from_slice_unchecked(&metadata_data[..]).unwrap();
+    let eligibility = if matches.is_present("collection") {
+        Eligibility::Collection
+    } else {
+        Eligibility::Creator
+    };
+
+    let creator = get_whitelist_key(&metadata_data, eligibility);
+
+    let (wl_data_address, _wl_data_address_bump) = Pubkey::find_program_address(
+        &[
+            "whitelist".as_bytes(),
+            &pool.to_bytes(),
+            &creator.to_bytes(),
+        ],
+        &program_id,
+    );
+
+    let boost = matches.value_of("hashlist").map(|hashlist| {
+        BoostTree::from_csv(hashlist)
+            .proof(&nft)
+            .expect("NFT is not in the hashlist")
+    });
 
-    let creator = metadata_data_struct
-        .data
-        .creators
-        .unwrap()
-        .first()
-        .unwrap()
-        .address;
+    let lock_tier = matches
+        .value_of("lock_tier")
+        .map(|lock_tier| lock_tier.parse::<u8>().unwrap());
 
-    let (wl_data_address, _wl_data_address_bump) =
-        Pubkey::find_program_address(&["whitelist".as_bytes(), &creator.to_bytes()], &program_id);
+    // the new position gets the next receipt mint
+    let stakes = get_stakes(&client, &program_id, &pool, &nft) + 1;
 
     let instructions = vec![Instruction::new_with_borsh(
         program_id,
-        &PlatformInstruction::Stake,
+        &PlatformInstruction::Stake {
+            pool_id,
+            eligibility,
+            boost,
+            lock_tier,
+        },
         vec![
             AccountMeta::new(wallet_pubkey, true),
             AccountMeta::new_readonly(nft, false),
             AccountMeta::new_readonly(metadata, false),
-            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(vault, false),
             AccountMeta::new(source, false),
             AccountMeta::new(destination, false),
             AccountMeta::new_readonly(spl_token::id(), false),
@@ -78,6 +122,17 @@ pub fn stake(matches: &ArgMatches) {
             AccountMeta::new_readonly(ASSOCIATED_TOKEN.parse::<Pubkey>().unwrap(), false),
             AccountMeta::new(stake_data, false),
             AccountMeta::new(wl_data_address, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(stats, false),
+            AccountMeta::new(staker, false),
+            AccountMeta::new_readonly(get_edition_address(&nft), false),
+            AccountMeta::new_readonly(spl_token_metadata::ID, false),
+            AccountMeta::new(get_receipt_address(&program_id, &pool, &nft, stakes), false),
+            AccountMeta::new(
+                get_receipt_holder(&program_id, &pool, &nft, stakes, &wallet_pubkey),
+                false,
+            ),
         ],
     )];
 
@@ -0,0 +1,81 @@
+use crate::consts::PROGRAM_ID;
+use crate::structs::{PlatformInstruction, Pool};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::borsh::try_from_slice_unchecked;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::transaction::Transaction;
+
+pub fn update_reward_schedule(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    // fields that are not passed keep their current value
+    let pool_data = client.get_account_data(&pool).unwrap();
+    let mut schedule = try_from_slice_unchecked::<Pool>(&pool_data)
+        .unwrap()
+        .epochs
+        .last()
+        .unwrap()
+        .schedule;
+
+    if let Some(interval) = matches.value_of("interval") {
+        schedule.interval = interval.parse::<u64>().unwrap();
+    }
+
+    if let Some(payout_per_interval) = matches.value_of("payout_per_interval") {
+        schedule.payout_per_interval = payout_per_interval.parse::<u64>().unwrap();
+    }
+
+    if let Some(warmup_intervals) = matches.value_of("warmup_intervals") {
+        schedule.warmup_intervals = warmup_intervals.parse::<u64>().unwrap();
+    }
+
+    if let Some(period_intervals) = matches.value_of("period_intervals") {
+        schedule.period_intervals = period_intervals.parse::<u64>().unwrap();
+    }
+
+    if let Some(max_payout_per_nft) = matches.value_of("max_payout_per_nft") {
+        schedule.max_payout_per_nft = max_payout_per_nft.parse::<u64>().unwrap();
+    }
+
+    let instructions = vec![Instruction::new_with_borsh(
+        program_id,
+        &PlatformInstruction::UpdateRewardSchedule { pool_id, schedule },
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(pool, false),
+        ],
+    )];
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
+    let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+    tx.sign(&vec![&wallet_keypair], recent_blockhash);
+    let id = client.send_transaction(&tx).expect("Transaction failed.");
+    println!("schedule: {:?}", schedule);
+    println!("tx id: {:?}", id);
+}
@@ -0,0 +1,95 @@
+use crate::consts::PROGRAM_ID;
+use crate::structs::{PlatformInstruction, WhitelistData};
+use clap::ArgMatches;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::borsh::try_from_slice_unchecked;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+#[allow(unused_imports)]
+use solana_sdk::signer::keypair::Keypair;
+#[allow(unused_imports)]
+use solana_sdk::signer::signers::Signers;
+use solana_sdk::transaction::Transaction;
+
+pub fn update_whitelist(matches: &ArgMatches) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+
+    let url = match matches.value_of("env") {
+        Some("dev") => "https://api.devnet.solana.com",
+        _ => "https://api.mainnet-beta.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+    let wallet_path = matches.value_of("sign").unwrap();
+    let wallet_keypair = read_keypair_file(wallet_path).expect("Can't open file-wallet");
+    let wallet_pubkey = wallet_keypair.pubkey();
+
+    let creator = matches
+        .value_of("collection")
+        .or_else(|| matches.value_of("creator"))
+        .unwrap()
+        .parse::<Pubkey>()
+        .unwrap();
+
+    let pool_id = matches.value_of("pool").unwrap().parse::<u64>().unwrap();
+
+    let (pool, _) =
+        Pubkey::find_program_address(&["pool".as_bytes(), &pool_id.to_le_bytes()], &program_id);
+
+    let (wl_address, _) = Pubkey::find_program_address(
+        &[
+            "whitelist".as_bytes(),
+            &pool.to_bytes(),
+            &creator.to_bytes(),
+        ],
+        &program_id,
+    );
+    println!("{:?}", wl_address.clone());
+
+    // fields that are not passed keep their current value
+    let whitelist_data = client.get_account_data(&wl_address).unwrap();
+    let whitelist = try_from_slice_unchecked::<WhitelistData>(&whitelist_data).unwrap();
+
+    let reward_multiplier = match matches.value_of("multiplier") {
+        Some(multiplier) => multiplier.parse::<u64>().unwrap(),
+        None => whitelist.reward_multiplier,
+    };
+
+    let enabled = if matches.is_present("enable") {
+        true
+    } else if matches.is_present("disable") {
+        false
+    } else {
+        whitelist.enabled
+    };
+
+    let (config, _) = Pubkey::find_program_address(&["config".as_bytes()], &program_id);
+
+    let instructions = vec![Instruction::new_with_borsh(
+        program_id,
+        &PlatformInstruction::UpdateWhitelist {
+            pool_id,
+            reward_multiplier,
+            enabled,
+        },
+        vec![
+            AccountMeta::new(wallet_pubkey, true),
+            AccountMeta::new_readonly(creator, false),
+            AccountMeta::new(wl_address, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(pool, false),
+        ],
+    )];
+
+    let mut tx = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
+    let recent_blockhash = client.get_latest_blockhash().expect("Can't get blockhash");
+    tx.sign(&vec![&wallet_keypair], recent_blockhash);
+    let id = client.send_transaction(&tx).expect("Transaction failed.");
+    println!(
+        "reward multiplier: {:?}, enabled: {:?}",
+        reward_multiplier, enabled
+    );
+    println!("tx id: {:?}", id);
+}